@@ -1,83 +1,222 @@
+use crate::history::{self, History, Retention};
+use crate::perfecter::PerfecterProvider;
 use crate::platform::imp::cpu;
+use crate::timer::{Sample, Sampler};
 use crate::types::cpu::{CoreLoadInfo, CoresLoadInfo, LoadAvg};
+use crate::types::{Error, Result};
 use crate::Timer;
+#[cfg(feature = "async")]
+use futures::{future, Stream, StreamExt};
 
-#[derive(Clone)]
-struct State {
-    prev: CoresLoadInfo,
-    current: Option<CoresLoadInfo>,
+use std::sync::{
+    mpsc::{channel, Receiver},
+    Arc, Mutex,
+};
+use std::time::Duration;
+
+#[derive(Clone, Default)]
+pub(crate) struct State {
+    prev: Option<CoresLoadInfo>,
+    pub(crate) current: Option<CoresLoadInfo>,
+    error: Option<Error>,
 }
 
 pub struct Cpu {
     timer: Timer<Option<State>>,
+    history: Arc<Mutex<History<f64>>>,
+}
+
+fn percent(cores: &CoresLoadInfo) -> Option<f64> {
+    let (used, total) = cores.iter().fold((0, 0), |(used, total), core| {
+        let busy = core.system + core.user;
+        (used + busy, total + busy + core.idle)
+    });
+
+    if total == 0 {
+        None
+    } else {
+        Some(100f64 * ((used as f64) / (total as f64)))
+    }
+}
+
+fn current(state: &Option<State>) -> Option<CoresLoadInfo> {
+    state.as_ref()?.current.clone()
+}
+
+pub(crate) fn sample(state: Option<State>, provider: Arc<PerfecterProvider>) -> Option<State> {
+    sample_with(state, provider, cpu::load)
+}
+
+fn sample_with<L>(state: Option<State>, provider: Arc<PerfecterProvider>, load: L) -> Option<State>
+where
+    L: FnOnce() -> Result<CoresLoadInfo>,
+{
+    let state = state.unwrap_or_default();
+    let next_prev = match load() {
+        Ok(load) => load,
+        Err(error) => {
+            return Some(State {
+                prev: state.prev,
+                current: None,
+                error: Some(error),
+            })
+        }
+    };
+    let perfecter = provider.get();
+
+    let current = match (state.prev, perfecter) {
+        (Some(prev), Some(perfecter)) if prev.len() == next_prev.len() => {
+            let mut current = Vec::with_capacity(prev.len());
+
+            for (next, prev) in next_prev.iter().zip(prev.iter()) {
+                current.push(CoreLoadInfo {
+                    system: perfecter.perfect(&next.system.saturating_sub(prev.system)),
+                    user: perfecter.perfect(&next.user.saturating_sub(prev.user)),
+                    idle: perfecter.perfect(&next.idle.saturating_sub(prev.idle)),
+                });
+            }
+
+            Some(current)
+        }
+        _ => None,
+    };
+
+    Some(State {
+        prev: Some(next_prev),
+        current,
+        error: None,
+    })
 }
 
 impl Cpu {
     pub fn new() -> Cpu {
-        Cpu {
-            timer: Timer::new(
-                None,
-                std::time::Duration::from_secs(1),
-                move |state, provider| match state {
-                    None => {
-                        provider.get();
-                        let s = cpu::load().ok()?;
-                        Some(State {
-                            prev: s,
-                            current: None,
-                        })
-                    }
-                    Some(state) => {
-                        let next_prev = cpu::load().ok()?;
-                        let perfecter = match provider.get() {
-                            None => {
-                                return Some(State {
-                                    prev: next_prev,
-                                    current: None,
-                                })
-                            }
-                            Some(p) => p,
-                        };
-
-                        if state.prev.len() != next_prev.len() {
-                            Some(State {
-                                prev: next_prev,
-                                current: None,
-                            })
-                        } else {
-                            let prev = state.prev;
-                            let mut current = Vec::with_capacity(prev.len());
-
-                            for i in 0..prev.len() {
-                                current.push(CoreLoadInfo {
-                                    system: perfecter
-                                        .perfect(&(next_prev[i].system - prev[i].system)),
-                                    user: perfecter.perfect(&(next_prev[i].user - prev[i].user)),
-                                    idle: perfecter.perfect(&(next_prev[i].idle - prev[i].idle)),
-                                });
-                            }
-
-                            Some(State {
-                                prev: next_prev,
-                                current: Some(current),
-                            })
-                        }
-                    }
-                },
-            ),
+        Cpu::with_interval(Duration::from_secs(1))
+    }
+
+    pub fn with_interval(interval: Duration) -> Cpu {
+        Cpu::from_timer(Timer::new(None, interval, sample))
+    }
+
+    pub fn with_sampler(sampler: &Sampler) -> Cpu {
+        Cpu::from_timer(Timer::shared(sampler, None, sample))
+    }
+
+    fn from_timer(timer: Timer<Option<State>>) -> Cpu {
+        let history = history::record(
+            &timer,
+            Retention::Duration(Duration::from_secs(15 * 60)),
+            |state| percent(state.as_ref()?.current.as_ref()?),
+        );
+
+        Cpu { timer, history }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn stream(interval: Duration) -> impl Stream<Item = CoresLoadInfo> + Send {
+        crate::timer::stream::perfected(interval, None, sample)
+            .filter_map(|state| future::ready(state.and_then(|state| state.current)))
+    }
+
+    pub fn load(&self) -> Result<CoresLoadInfo> {
+        let state = self.timer.get().unwrap_or_default();
+
+        match state.error {
+            Some(error) => Err(error),
+            None => state
+                .current
+                .ok_or_else(|| Error::not_ready("No CPU sample available yet")),
         }
     }
 
-    pub fn load(&self) -> Option<CoresLoadInfo> {
-        self.timer.get()?.current
+    pub fn last_error(&self) -> Option<Error> {
+        self.timer.get()?.error
+    }
+
+    pub fn history<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&History<f64>) -> R,
+    {
+        f(&self.history.lock().unwrap())
+    }
+
+    pub fn set_history_retention(&self, retention: Retention) {
+        self.history.lock().unwrap().set_retention(retention)
+    }
+
+    pub fn subscribe<F>(&self, mut f: F)
+    where
+        F: FnMut(&Sample<CoresLoadInfo>),
+        F: Send + 'static,
+    {
+        self.timer.subscribe(move |sample| {
+            if let Some(sample) = sample.and_then(current) {
+                f(&sample);
+            }
+        })
+    }
+
+    pub fn watch(&self) -> Receiver<Sample<CoresLoadInfo>> {
+        let (tx, rx) = channel();
+        self.timer
+            .subscribe_while(move |sample| match sample.and_then(current) {
+                Some(sample) => tx.send(sample).is_ok(),
+                None => true,
+            });
+        rx
     }
 
-    pub fn loadavg(&self) -> Option<LoadAvg> {
+    pub fn loadavg(&self) -> Result<LoadAvg> {
         cpu::loadavg()
     }
 
+    pub fn interval(&self) -> Duration {
+        self.timer.interval()
+    }
+
+    pub fn set_interval(&self, interval: Duration) -> Result<()> {
+        self.timer.set_interval(interval)
+    }
+
     pub fn close(self) {
-        (&self).timer.stop().unwrap();
-        self.timer.join().unwrap();
+        let _ = self.timer.stop();
+        let _ = self.timer.join();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{sample_with, State};
+    use crate::perfecter::PerfecterProvider;
+    use crate::types::cpu::CoreLoadInfo;
+    use crate::types::{Error, ErrorKind};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn core(system: usize, user: usize, idle: usize) -> CoreLoadInfo {
+        CoreLoadInfo { system, user, idle }
+    }
+
+    #[test]
+    fn failed_read_keeps_baseline() {
+        let provider = Arc::new(PerfecterProvider::new(Duration::from_secs(1)));
+        provider.get();
+        let state = Some(State {
+            prev: Some(vec![core(10, 20, 100)]),
+            current: None,
+            error: None,
+        });
+
+        let state = sample_with(state, provider.clone(), || Err(Error::not_found("gone")));
+        let failed = state.clone().unwrap();
+        assert_eq!(failed.error.unwrap().kind(), &ErrorKind::NotFound);
+        assert!(failed.current.is_none());
+        assert_eq!(failed.prev.unwrap()[0].idle, 100);
+
+        // idle includes iowait on Linux, which can go backwards
+        let state = sample_with(state, provider, || Ok(vec![core(15, 30, 90)])).unwrap();
+        let current = state.current.unwrap();
+        assert!(state.error.is_none());
+        assert_eq!(current[0].idle, 0);
+        assert!(current[0].user > 0);
     }
 }
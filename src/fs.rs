@@ -1,4 +1,5 @@
 use crate::platform::imp::fs;
+use crate::types::{Error, Result};
 use std::path::Path;
 
 pub struct FileSystem;
@@ -8,42 +9,42 @@ impl FileSystem {
         FileSystem
     }
 
-    pub fn all(&self) -> Option<Vec<crate::types::fs::FileSystem>> {
-        fs::all().ok()
+    pub fn all(&self) -> Result<Vec<crate::types::fs::FileSystem>> {
+        fs::all()
     }
 
-    pub fn for_device<P: AsRef<Path>>(&self, device: P) -> Option<crate::types::fs::FileSystem> {
-        let device = device.as_ref().canonicalize().ok()?;
+    fn find<F>(&self, what: &str, f: F) -> Result<crate::types::fs::FileSystem>
+    where
+        F: Fn(&crate::types::fs::FileSystem) -> bool,
+    {
         self.all()?
             .into_iter()
-            .filter(|fs| fs.device == device)
-            .next()
+            .find(f)
+            .ok_or_else(|| Error::not_found(format!("No filesystem for {}", what)))
     }
 
-    pub fn for_mountpoint<P: AsRef<Path>>(&self, path: P) -> Option<crate::types::fs::FileSystem> {
-        let path = path.as_ref().canonicalize().ok()?;
-        self.all()?
-            .into_iter()
-            .filter(|fs| fs.mountpoint == path)
-            .next()
+    pub fn for_device<P: AsRef<Path>>(&self, device: P) -> Result<crate::types::fs::FileSystem> {
+        let device = device.as_ref().canonicalize()?;
+        self.find(&device.to_string_lossy(), |fs| fs.device == device)
     }
 
-    pub fn for_label(&self, label: &str) -> Option<crate::types::fs::FileSystem> {
-        self.all()?
-            .into_iter()
-            .filter(|fs| fs.label == label)
-            .next()
+    pub fn for_mountpoint<P: AsRef<Path>>(&self, path: P) -> Result<crate::types::fs::FileSystem> {
+        let path = path.as_ref().canonicalize()?;
+        self.find(&path.to_string_lossy(), |fs| fs.mountpoint == path)
     }
 
-    pub fn for_uuid(&self, uuid: &str) -> Option<crate::types::fs::FileSystem> {
-        self.all()?.into_iter().filter(|fs| fs.uuid == uuid).next()
+    pub fn for_label(&self, label: &str) -> Result<crate::types::fs::FileSystem> {
+        self.find(label, |fs| fs.label == label)
     }
 
-    pub fn containing_path<P: AsRef<Path>>(&self, path: P) -> Option<crate::types::fs::FileSystem> {
-        let path = path.as_ref().canonicalize().ok()?;
-        self.all()?
-            .into_iter()
-            .filter(|fs| path.starts_with(&fs.mountpoint))
-            .next()
+    pub fn for_uuid(&self, uuid: &str) -> Result<crate::types::fs::FileSystem> {
+        self.find(uuid, |fs| fs.uuid == uuid)
+    }
+
+    pub fn containing_path<P: AsRef<Path>>(&self, path: P) -> Result<crate::types::fs::FileSystem> {
+        let path = path.as_ref().canonicalize()?;
+        self.find(&path.to_string_lossy(), |fs| {
+            path.starts_with(&fs.mountpoint)
+        })
     }
 }
@@ -0,0 +1,250 @@
+use crate::Timer;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Retention {
+    Count(usize),
+    Duration(Duration),
+}
+
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    retention: Retention,
+    samples: VecDeque<(Instant, T)>,
+}
+
+impl<T> History<T> {
+    pub fn new(retention: Retention) -> History<T> {
+        History {
+            retention,
+            samples: VecDeque::new(),
+        }
+    }
+
+    pub fn retention(&self) -> Retention {
+        self.retention
+    }
+
+    pub fn set_retention(&mut self, retention: Retention) {
+        self.retention = retention;
+        self.trim(Instant::now());
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.push_at(Instant::now(), value)
+    }
+
+    pub fn push_at(&mut self, at: Instant, value: T) {
+        self.samples.push_back((at, value));
+        self.trim(at);
+    }
+
+    fn trim(&mut self, now: Instant) {
+        match self.retention {
+            Retention::Count(count) => {
+                while self.samples.len() > count {
+                    self.samples.pop_front();
+                }
+            }
+            Retention::Duration(duration) => {
+                while self
+                    .samples
+                    .front()
+                    .is_some_and(|(at, _)| now.duration_since(*at) > duration)
+                {
+                    self.samples.pop_front();
+                }
+            }
+        }
+    }
+
+    fn records(&self) -> bool {
+        self.retention != Retention::Count(0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn latest(&self) -> Option<&T> {
+        self.samples.back().map(|(_, value)| value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Instant, &T)> {
+        self.samples.iter().map(|(at, value)| (*at, value))
+    }
+
+    pub fn window(&self, window: Duration) -> impl Iterator<Item = &T> {
+        self.timed(window).map(|(_, value)| value)
+    }
+
+    fn timed(&self, window: Duration) -> impl Iterator<Item = (Instant, &T)> {
+        let now = Instant::now();
+
+        self.iter()
+            .filter(move |(at, _)| now.duration_since(*at) <= window)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> History<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        History {
+            retention: self.retention,
+            samples: self.iter().map(|(at, value)| (at, f(value))).collect(),
+        }
+    }
+}
+
+// Retention::Count(0) keeps nothing, so the projection is skipped entirely
+// until someone asks for a history.
+pub(crate) fn record<T, V, F>(
+    timer: &Timer<T>,
+    retention: Retention,
+    f: F,
+) -> Arc<Mutex<History<V>>>
+where
+    T: Clone + Send + 'static,
+    V: Send + 'static,
+    F: Fn(&T) -> Option<V>,
+    F: Send + 'static,
+{
+    let history = Arc::new(Mutex::new(History::new(retention)));
+    let h = history.clone();
+
+    timer.subscribe(move |sample| {
+        let mut history = h.lock().unwrap();
+        if !history.records() {
+            return;
+        }
+        if let Some(value) = f(&sample.value) {
+            history.push(value);
+        }
+    });
+
+    history
+}
+
+impl<T: Copy + Into<f64>> History<T> {
+    fn values(&self, window: Duration) -> Vec<f64> {
+        self.window(window).map(|value| (*value).into()).collect()
+    }
+
+    pub fn min(&self, window: Duration) -> Option<f64> {
+        self.values(window).into_iter().reduce(f64::min)
+    }
+
+    pub fn max(&self, window: Duration) -> Option<f64> {
+        self.values(window).into_iter().reduce(f64::max)
+    }
+
+    pub fn mean(&self, window: Duration) -> Option<f64> {
+        let values = self.values(window);
+
+        if values.is_empty() {
+            None
+        } else {
+            Some(values.iter().sum::<f64>() / values.len() as f64)
+        }
+    }
+
+    pub fn percentile(&self, window: Duration, p: f64) -> Option<f64> {
+        let mut values = self.values(window);
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(|a, b| a.total_cmp(b));
+
+        let rank = (p.clamp(0.0, 100.0) / 100.0) * (values.len() - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+
+        Some(values[lower] + (values[upper] - values[lower]) * (rank - lower as f64))
+    }
+
+    pub fn ewma(&self, window: Duration) -> Option<f64> {
+        let mut samples = self.timed(window);
+        let (mut prev, first) = samples.next()?;
+        let mut res: f64 = (*first).into();
+
+        for (at, value) in samples {
+            let dt = at.duration_since(prev).as_secs_f64();
+            let alpha = if window.as_secs_f64() > 0.0 {
+                1.0 - (-dt / window.as_secs_f64()).exp()
+            } else {
+                1.0
+            };
+
+            res += alpha * ((*value).into() - res);
+            prev = at;
+        }
+
+        Some(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{History, Retention};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn window_stats() {
+        let mut history = History::new(Retention::Count(4));
+        for value in [10.0, 20.0, 30.0, 40.0, 50.0] {
+            history.push(value);
+        }
+
+        let window = Duration::from_secs(60);
+        assert_eq!(history.len(), 4);
+        assert_eq!(history.min(window), Some(20.0));
+        assert_eq!(history.max(window), Some(50.0));
+        assert_eq!(history.mean(window), Some(35.0));
+        assert_eq!(history.percentile(window, 50.0), Some(35.0));
+        assert_eq!(history.percentile(window, 100.0), Some(50.0));
+    }
+
+    #[test]
+    fn duration_retention_and_ewma() {
+        let now = Instant::now();
+        let mut history = History::new(Retention::Duration(Duration::from_secs(10)));
+        for i in 0..20u64 {
+            if let Some(at) = now.checked_sub(Duration::from_secs(19 - i)) {
+                history.push_at(at, if i < 12 { 0.0 } else { 100.0 });
+            }
+        }
+
+        assert_eq!(history.len(), 11);
+        assert_eq!(history.window(Duration::from_millis(4500)).count(), 5);
+        assert_eq!(history.min(Duration::from_millis(4500)), Some(100.0));
+        assert_eq!(history.min(Duration::from_secs(60)), Some(0.0));
+        let ewma = history.ewma(Duration::from_secs(10)).unwrap();
+        assert!(ewma > 50.0 && ewma < 100.0);
+
+        history.set_retention(Retention::Count(0));
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn ewma_window() {
+        let now = Instant::now();
+        let mut history = History::new(Retention::Count(32));
+        for i in 0..20u64 {
+            if let Some(at) = now.checked_sub(Duration::from_secs(19 - i)) {
+                history.push_at(at, if i < 15 { 0.0 } else { 100.0 });
+            }
+        }
+
+        assert_eq!(history.ewma(Duration::from_millis(3500)), Some(100.0));
+        assert!(history.ewma(Duration::from_secs(60)).unwrap() < 100.0);
+        assert_eq!(
+            history.map(|v| v / 100.0).max(Duration::from_secs(60)),
+            Some(1.0)
+        );
+    }
+}
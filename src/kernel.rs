@@ -0,0 +1,187 @@
+use crate::history::{self, History, Retention};
+use crate::perfecter::{Perfecter, PerfecterProvider};
+use crate::platform::imp::kernel;
+use crate::timer::Sampler;
+use crate::types::kernel::{Irq, KernelActivity, KernelRates};
+use crate::types::rate::PerSecond;
+use crate::types::{Error, Result};
+use crate::Timer;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Clone, Default)]
+pub(crate) struct State {
+    prev: Option<KernelActivity>,
+    pub(crate) current: Option<KernelActivity>,
+    rates: Option<KernelRates>,
+    error: Option<Error>,
+}
+
+pub struct Kernel {
+    timer: Timer<Option<State>>,
+    history: Arc<Mutex<History<KernelActivity>>>,
+}
+
+fn deltas(perfecter: &Perfecter, next: &KernelActivity, prev: &KernelActivity) -> KernelActivity {
+    let mut irqs = Vec::with_capacity(next.irqs.len());
+    for irq in next.irqs.iter() {
+        let old = match prev.irqs.iter().find(|i| i.name == irq.name) {
+            Some(old) if old.per_cpu.len() == irq.per_cpu.len() => old,
+            _ => continue,
+        };
+
+        irqs.push(Irq {
+            name: irq.name.clone(),
+            description: irq.description.clone(),
+            per_cpu: irq
+                .per_cpu
+                .iter()
+                .zip(old.per_cpu.iter())
+                .map(|(n, p)| perfecter.perfect(&n.saturating_sub(*p)))
+                .collect(),
+        });
+    }
+
+    KernelActivity {
+        context_switches: perfecter
+            .perfect(&next.context_switches.saturating_sub(prev.context_switches)),
+        interrupts: perfecter.perfect(&next.interrupts.saturating_sub(prev.interrupts)),
+        soft_interrupts: perfecter
+            .perfect(&next.soft_interrupts.saturating_sub(prev.soft_interrupts)),
+        forks: perfecter.perfect(&next.forks.saturating_sub(prev.forks)),
+        procs_running: next.procs_running,
+        procs_blocked: next.procs_blocked,
+        irqs,
+    }
+}
+
+fn rates(elapsed: Duration, next: &KernelActivity, prev: &KernelActivity) -> KernelRates {
+    let count = |n: u64, p: u64| PerSecond::from_delta(n.saturating_sub(p), elapsed);
+
+    KernelRates {
+        context_switches: count(next.context_switches, prev.context_switches),
+        interrupts: count(next.interrupts, prev.interrupts),
+        soft_interrupts: count(next.soft_interrupts, prev.soft_interrupts),
+        forks: count(next.forks, prev.forks),
+    }
+}
+
+pub(crate) fn sample(state: Option<State>, provider: Arc<PerfecterProvider>) -> Option<State> {
+    let state = state.unwrap_or_default();
+    let next_prev = match kernel::activity() {
+        Ok(activity) => activity,
+        Err(error) => {
+            return Some(State {
+                prev: state.prev,
+                current: None,
+                rates: None,
+                error: Some(error),
+            })
+        }
+    };
+
+    let (current, rates) = match (state.prev, provider.get()) {
+        (Some(prev), Some(perfecter)) => (
+            Some(deltas(&perfecter, &next_prev, &prev)),
+            Some(rates(*perfecter.actual_duration(), &next_prev, &prev)),
+        ),
+        _ => (None, None),
+    };
+
+    Some(State {
+        prev: Some(next_prev),
+        current,
+        rates,
+        error: None,
+    })
+}
+
+impl Kernel {
+    pub fn new() -> Kernel {
+        Kernel::with_interval(Duration::from_secs(1))
+    }
+
+    pub fn with_interval(interval: Duration) -> Kernel {
+        Kernel::from_timer(Timer::new(None, interval, sample))
+    }
+
+    pub fn with_sampler(sampler: &Sampler) -> Kernel {
+        Kernel::from_timer(Timer::shared(sampler, None, sample))
+    }
+
+    fn from_timer(timer: Timer<Option<State>>) -> Kernel {
+        let history = history::record(&timer, Retention::Count(0), |state| {
+            state.as_ref()?.current.clone()
+        });
+
+        Kernel { timer, history }
+    }
+
+    pub fn activity(&self) -> Result<KernelActivity> {
+        let state = self.timer.get().unwrap_or_default();
+
+        match state.error {
+            Some(error) => Err(error),
+            None => state
+                .current
+                .ok_or_else(|| Error::not_ready("No kernel activity sample available yet")),
+        }
+    }
+
+    pub fn rates(&self) -> Result<KernelRates> {
+        let state = self.timer.get().unwrap_or_default();
+
+        match state.error {
+            Some(error) => Err(error),
+            None => state
+                .rates
+                .ok_or_else(|| Error::not_ready("No kernel activity sample available yet")),
+        }
+    }
+
+    pub fn totals(&self) -> Result<KernelActivity> {
+        let state = self.timer.get().unwrap_or_default();
+
+        match state.error {
+            Some(error) => Err(error),
+            None => state
+                .prev
+                .ok_or_else(|| Error::not_ready("No kernel activity sample available yet")),
+        }
+    }
+
+    pub fn last_error(&self) -> Option<Error> {
+        self.timer.get()?.error
+    }
+
+    pub fn history<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&History<KernelActivity>) -> R,
+    {
+        f(&self.history.lock().unwrap())
+    }
+
+    pub fn set_history_retention(&self, retention: Retention) {
+        self.history.lock().unwrap().set_retention(retention)
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.timer.interval()
+    }
+
+    pub fn set_interval(&self, interval: Duration) -> Result<()> {
+        self.timer.set_interval(interval)
+    }
+
+    pub fn close(self) {
+        let _ = self.timer.stop();
+        let _ = self.timer.join();
+    }
+}
+
+impl Default for Kernel {
+    fn default() -> Kernel {
+        Kernel::new()
+    }
+}
@@ -2,31 +2,52 @@
 extern crate cfg_if;
 #[cfg(unix)]
 extern crate errno;
+#[cfg(feature = "async")]
+extern crate futures;
 extern crate libc;
+#[cfg(feature = "rug")]
 extern crate rug;
+#[cfg(feature = "async")]
+extern crate tokio;
 
+pub mod history;
 pub mod perfecter;
 pub mod timer;
 pub mod types;
 
+pub use history::History;
 pub use perfecter::Perfecter;
-pub use timer::Timer;
+pub use timer::{Sample, Sampler, Timer};
 
 pub mod platform;
-pub use platform::imp::*;
+pub use platform::imp::{boottime, environment, host, limits};
 
 mod cpu;
 mod fs;
+mod kernel;
 mod mem;
 mod misc;
 mod network;
 mod power;
+mod protocols;
+#[cfg(feature = "async")]
+mod snapshot;
+mod sockets;
 mod thermal;
+mod users;
+mod wifi;
 
 pub use cpu::Cpu;
 pub use fs::FileSystem;
+pub use kernel::Kernel;
 pub use mem::Memory;
 pub use misc::Misc;
-pub use network::Network;
+pub use network::{Network, NetworkBuilder};
 pub use power::Power;
+pub use protocols::Protocols;
+#[cfg(feature = "async")]
+pub use snapshot::Snapshot;
+pub use sockets::Sockets;
 pub use thermal::Thermal;
+pub use users::Users;
+pub use wifi::WiFi;
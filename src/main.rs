@@ -6,9 +6,16 @@ fn main() {
     let mem = tomography::Memory::new();
     let therm = tomography::Thermal::new();
     let power = tomography::Power::new();
-    let cpu = tomography::Cpu::new();
-    let net = tomography::Network::new();
+    let sampler = tomography::Sampler::new(std::time::Duration::from_secs(1));
+    let cpu = tomography::Cpu::with_sampler(&sampler);
+    let net = tomography::Network::with_sampler(&sampler);
+    let users = tomography::Users::new();
+    let kernel = tomography::Kernel::with_sampler(&sampler);
+    let sockets = tomography::Sockets::new();
+    let protocols = tomography::Protocols::with_sampler(&sampler);
+    let wifi = tomography::WiFi::new();
 
+    println!("{:#?}", wifi.scan());
     println!("Waiting 1 second...");
 
     loop {
@@ -16,7 +23,13 @@ fn main() {
         println!("{:#?}", cpu.load());
         println!("{:#?}", cpu.loadavg());
         println!("{:#?}", net.interfaces());
+        println!("{:#?}", net.topology());
+        println!("{:#?}", net.default_gateway());
+        println!("{:#?}", net.dns());
         println!("{:#?}", misc.boot_time());
+        println!("{:#?}", misc.environment());
+        println!("{:#?}", misc.host());
+        println!("{:#?}", misc.limits());
         println!("{:#?}", fs.all());
         println!("{:#?}", mem.ram());
         println!("{:#?}", mem.swap());
@@ -24,8 +37,9 @@ fn main() {
         println!("{:#?}", therm.cpus());
         println!("{:#?}", therm.custom("TA0P"));
         println!("{:#?}", power.sources());
+        println!("{:#?}", users.sessions());
+        println!("{:#?}", kernel.activity());
+        println!("{:#?}", sockets.tcp_summary());
+        println!("{:#?}", protocols.counters());
     }
-
-    // cpu.close();
-    // net.close();
 }
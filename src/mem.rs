@@ -1,5 +1,6 @@
 use crate::platform::imp::mem;
 use crate::types::mem::{Swap, RAM};
+use crate::types::Result;
 
 pub struct Memory;
 
@@ -8,11 +9,11 @@ impl Memory {
         Memory
     }
 
-    pub fn ram(&self) -> Option<RAM> {
-        mem::ram().ok()
+    pub fn ram(&self) -> Result<RAM> {
+        mem::ram()
     }
 
-    pub fn swap(&self) -> Option<Swap> {
-        mem::swap().ok()
+    pub fn swap(&self) -> Result<Swap> {
+        mem::swap()
     }
 }
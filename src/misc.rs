@@ -1,4 +1,7 @@
-use crate::platform::imp::boottime;
+use crate::platform::imp::{boottime, environment, host, limits};
+use crate::types::limits::Limits;
+use crate::types::misc::{Environment, Host};
+use crate::types::Result;
 use std::time::SystemTime;
 
 pub struct Misc;
@@ -8,7 +11,19 @@ impl Misc {
         Misc
     }
 
-    pub fn boot_time(&self) -> Option<SystemTime> {
+    pub fn boot_time(&self) -> Result<SystemTime> {
         boottime::get()
     }
+
+    pub fn environment(&self) -> Environment {
+        environment::get()
+    }
+
+    pub fn host(&self) -> Result<Host> {
+        host::get()
+    }
+
+    pub fn limits(&self) -> Result<Limits> {
+        limits::get()
+    }
 }
@@ -1,84 +1,322 @@
+use crate::history::{self, History, Retention};
+use crate::perfecter::{Perfecter, PerfecterProvider};
 use crate::platform::imp::network;
-use crate::types::network::NetworkInterface;
+use crate::timer::{Sample, Sampler};
+use crate::types::network::{Dns, InterfaceRates, InterfaceStats, Link, NetworkInterface, Route};
+use crate::types::rate::{BytesPerSecond, PerSecond, Ratio};
+use crate::types::{Error, Result};
 use crate::Timer;
+#[cfg(feature = "async")]
+use futures::{future, Stream, StreamExt};
 
 use std::collections::HashMap;
+use std::sync::{
+    mpsc::{channel, Receiver},
+    Arc, Mutex,
+};
+use std::time::Duration;
 
-#[derive(Clone)]
-struct State {
-    prev: HashMap<String, NetworkInterface>,
-    current: Option<Vec<NetworkInterface>>,
+#[derive(Clone, Default)]
+pub(crate) struct State {
+    prev: Option<HashMap<String, NetworkInterface>>,
+    pub(crate) current: Option<Vec<NetworkInterface>>,
+    error: Option<Error>,
 }
 
 pub struct Network {
     timer: Timer<Option<State>>,
+    history: Arc<Mutex<History<Vec<NetworkInterface>>>>,
 }
 
-fn all() -> Option<HashMap<String, NetworkInterface>> {
-    let ifs = network::all().ok()?;
+pub struct NetworkBuilder {
+    interval: Duration,
+}
+
+fn all() -> Result<HashMap<String, NetworkInterface>> {
+    let ifs = network::all()?;
     let mut hm = HashMap::with_capacity(ifs.len());
 
     for netif in ifs.into_iter() {
         hm.insert(netif.name.clone(), netif);
     }
 
-    Some(hm)
+    Ok(hm)
+}
+
+fn stats(perfecter: &Perfecter, next: &InterfaceStats, prev: &InterfaceStats) -> InterfaceStats {
+    let delta = |n: u64, p: u64| perfecter.perfect(&n.saturating_sub(p));
+
+    InterfaceStats {
+        rx_packets: delta(next.rx_packets, prev.rx_packets),
+        tx_packets: delta(next.tx_packets, prev.tx_packets),
+        rx_errors: delta(next.rx_errors, prev.rx_errors),
+        tx_errors: delta(next.tx_errors, prev.tx_errors),
+        rx_dropped: delta(next.rx_dropped, prev.rx_dropped),
+        tx_dropped: delta(next.tx_dropped, prev.tx_dropped),
+        collisions: delta(next.collisions, prev.collisions),
+        rx_multicast: delta(next.rx_multicast, prev.rx_multicast),
+        tx_multicast: delta(next.tx_multicast, prev.tx_multicast),
+    }
+}
+
+fn rates(elapsed: Duration, next: &NetworkInterface, prev: &NetworkInterface) -> InterfaceRates {
+    let bytes = |n: u64, p: u64| BytesPerSecond::from_delta(n.saturating_sub(p), elapsed);
+    let count = |n: u64, p: u64| PerSecond::from_delta(n.saturating_sub(p), elapsed);
+    let (nc, pc) = (&next.counters, &prev.counters);
+    let up = bytes(next.tx_bytes, prev.tx_bytes);
+    let down = bytes(next.rx_bytes, prev.rx_bytes);
+
+    InterfaceRates {
+        up,
+        down,
+        rx_packets: count(nc.rx_packets, pc.rx_packets),
+        tx_packets: count(nc.tx_packets, pc.tx_packets),
+        rx_errors: count(nc.rx_errors, pc.rx_errors),
+        tx_errors: count(nc.tx_errors, pc.tx_errors),
+        rx_dropped: count(nc.rx_dropped, pc.rx_dropped),
+        tx_dropped: count(nc.tx_dropped, pc.tx_dropped),
+        utilization: next
+            .speed
+            .map(|speed| Ratio::new(up.bits().max(down.bits()), speed as f64 * 1e6)),
+    }
+}
+
+fn current(state: &Option<State>) -> Option<Vec<NetworkInterface>> {
+    state.as_ref()?.current.clone()
+}
+
+pub(crate) fn sample(state: Option<State>, provider: Arc<PerfecterProvider>) -> Option<State> {
+    let state = state.unwrap_or_default();
+    let next_prev = match all() {
+        Ok(all) => all,
+        Err(error) => {
+            return Some(State {
+                prev: state.prev,
+                current: None,
+                error: Some(error),
+            })
+        }
+    };
+    let perfecter = provider.get();
+
+    let current = match (state.prev, perfecter) {
+        (Some(prev), Some(perfecter)) => {
+            let mut current = Vec::new();
+            for (k, v) in next_prev.iter() {
+                if let Some(old) = prev.get(k) {
+                    let mut netif = v.clone();
+                    netif.up = perfecter.perfect(&netif.up.saturating_sub(old.up));
+                    netif.down = perfecter.perfect(&netif.down.saturating_sub(old.down));
+                    netif.stats = stats(&perfecter, &netif.stats, &old.stats);
+                    netif.rates = Some(rates(*perfecter.actual_duration(), v, old));
+                    current.push(netif);
+                }
+            }
+
+            Some(current)
+        }
+        _ => None,
+    };
+
+    Some(State {
+        prev: Some(next_prev),
+        current,
+        error: None,
+    })
+}
+
+impl NetworkBuilder {
+    pub fn interval(mut self, interval: Duration) -> NetworkBuilder {
+        self.interval = interval;
+        self
+    }
+
+    pub fn build(self) -> Network {
+        Network::from_timer(Timer::new(None, self.interval, sample))
+    }
 }
 
 impl Network {
     pub fn new() -> Network {
-        Network {
-            timer: Timer::new(
-                None,
-                std::time::Duration::from_secs(1),
-                move |state, provider| match state {
-                    None => {
-                        provider.get();
-                        let prev = all()?;
-                        Some(State {
-                            prev,
-                            current: None,
-                        })
-                    }
-                    Some(state) => {
-                        let next_prev = all()?;
-                        let perfecter = match provider.get() {
-                            None => {
-                                return Some(State {
-                                    prev: next_prev,
-                                    current: None,
-                                })
-                            }
-                            Some(p) => p,
-                        };
-
-                        let prev = state.prev;
-                        let mut current = Vec::new();
-                        for (k, v) in next_prev.iter() {
-                            if prev.contains_key(k) {
-                                let mut netif = v.clone();
-                                netif.up = perfecter.perfect(&(netif.up - prev[k].up));
-                                netif.down = perfecter.perfect(&(netif.down - prev[k].down));
-                                current.push(netif);
-                            }
-                        }
-
-                        Some(State {
-                            prev: next_prev,
-                            current: Some(current),
-                        })
-                    }
-                },
-            ),
+        Network::builder().build()
+    }
+
+    pub fn builder() -> NetworkBuilder {
+        NetworkBuilder {
+            interval: Duration::from_secs(1),
+        }
+    }
+
+    pub fn with_sampler(sampler: &Sampler) -> Network {
+        Network::from_timer(Timer::shared(sampler, None, sample))
+    }
+
+    fn from_timer(timer: Timer<Option<State>>) -> Network {
+        let history = history::record(&timer, Retention::Count(0), |state| {
+            state.as_ref()?.current.clone()
+        });
+
+        Network { timer, history }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn stream(interval: Duration) -> impl Stream<Item = Vec<NetworkInterface>> + Send {
+        crate::timer::stream::perfected(interval, None, sample)
+            .filter_map(|state| future::ready(state.and_then(|state| state.current)))
+    }
+
+    pub fn interfaces(&self) -> Result<Vec<NetworkInterface>> {
+        let state = self.timer.get().unwrap_or_default();
+
+        match state.error {
+            Some(error) => Err(error),
+            None => state
+                .current
+                .ok_or_else(|| Error::not_ready("No network sample available yet")),
         }
     }
 
-    pub fn interfaces(&self) -> Option<Vec<NetworkInterface>> {
-        self.timer.get()?.current
+    pub fn last_error(&self) -> Option<Error> {
+        self.timer.get()?.error
+    }
+
+    pub fn history<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&History<Vec<NetworkInterface>>) -> R,
+    {
+        f(&self.history.lock().unwrap())
+    }
+
+    pub fn set_history_retention(&self, retention: Retention) {
+        self.history.lock().unwrap().set_retention(retention)
+    }
+
+    pub fn subscribe<F>(&self, mut f: F)
+    where
+        F: FnMut(&Sample<Vec<NetworkInterface>>),
+        F: Send + 'static,
+    {
+        self.timer.subscribe(move |sample| {
+            if let Some(sample) = sample.and_then(current) {
+                f(&sample);
+            }
+        })
+    }
+
+    pub fn watch(&self) -> Receiver<Sample<Vec<NetworkInterface>>> {
+        let (tx, rx) = channel();
+        self.timer
+            .subscribe_while(move |sample| match sample.and_then(current) {
+                Some(sample) => tx.send(sample).is_ok(),
+                None => true,
+            });
+        rx
+    }
+
+    pub fn topology(&self) -> Result<Vec<Link>> {
+        network::topology()
+    }
+
+    pub fn routes(&self) -> Result<Vec<Route>> {
+        network::routes()
+    }
+
+    pub fn default_gateway(&self) -> Result<Route> {
+        let mut defaults = self
+            .routes()?
+            .into_iter()
+            .filter(|route| route.is_default() && route.is_up() && route.gateway.is_some())
+            .collect::<Vec<_>>();
+        defaults.sort_by_key(|route| (route.destination.is_ipv6(), route.metric));
+        defaults
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::not_found("No default gateway"))
+    }
+
+    pub fn dns(&self) -> Result<Dns> {
+        network::dns()
+    }
+
+    pub fn interface_for(&self, route: &Route) -> Result<NetworkInterface> {
+        self.interfaces()?
+            .into_iter()
+            .find(|netif| netif.name == route.interface)
+            .ok_or_else(|| Error::not_found(format!("No interface {}", route.interface)))
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.timer.interval()
+    }
+
+    pub fn set_interval(&self, interval: Duration) -> Result<()> {
+        self.timer.set_interval(interval)
     }
 
     pub fn close(self) {
-        (&self).timer.stop().unwrap();
-        self.timer.join().unwrap();
+        let _ = self.timer.stop();
+        let _ = self.timer.join();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::rates;
+    use crate::types::network::{InterfaceStats, NetworkInterface, Type};
+    use crate::types::rate::{BytesPerSecond, PerSecond, Ratio};
+    use std::time::Duration;
+
+    fn netif(
+        tx_bytes: u64,
+        rx_bytes: u64,
+        rx_packets: u64,
+        speed: Option<u64>,
+    ) -> NetworkInterface {
+        NetworkInterface {
+            name: "eth0".to_string(),
+            display_name: None,
+            itype: Type::Wired,
+            is_up: true,
+            bssid: None,
+            ssid: None,
+            wireless: None,
+            up: 0,
+            down: 0,
+            stats: Default::default(),
+            tx_bytes,
+            rx_bytes,
+            counters: InterfaceStats {
+                rx_packets,
+                ..Default::default()
+            },
+            rates: None,
+            mac: None,
+            mtu: None,
+            addresses: vec![],
+            speed,
+            duplex: None,
+            carrier: None,
+        }
+    }
+
+    #[test]
+    fn interface_rates() {
+        let prev = netif(1_000, 2_000, 10, Some(1));
+        let next = netif(63_500, 4_000, 30, Some(1));
+        let rate = rates(Duration::from_millis(500), &next, &prev);
+        assert_eq!(rate.up, BytesPerSecond(125_000f64));
+        assert_eq!(rate.down, BytesPerSecond(4_000f64));
+        assert_eq!(rate.rx_packets, PerSecond(40f64));
+        assert_eq!(rate.utilization, Some(Ratio(1f64)));
+
+        let reset = rates(Duration::from_secs(1), &netif(0, 0, 0, None), &prev);
+        assert_eq!(reset.up, BytesPerSecond(0f64));
+        assert_eq!(reset.down, BytesPerSecond(0f64));
+        assert_eq!(reset.rx_packets, PerSecond(0f64));
+        assert_eq!(reset.utilization, None);
+
+        let instant = rates(Duration::from_secs(0), &next, &prev);
+        assert_eq!(instant.up, BytesPerSecond(0f64));
+        assert_eq!(instant.utilization, Some(Ratio(0f64)));
     }
 }
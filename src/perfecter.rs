@@ -1,4 +1,6 @@
-use rug::{Float, Integer, Rational};
+#[cfg(feature = "rug")]
+use rug::{Integer, Rational};
+use std::convert::TryFrom;
 use std::sync::RwLock;
 use std::time::{Duration, Instant};
 
@@ -17,6 +19,19 @@ impl Perfecter {
         &self.actual_duration
     }
 
+    pub fn ratio(&self) -> (u128, u128) {
+        match self.actual_duration().as_nanos() {
+            0 => (1, 1),
+            actual => (self.expected_duration().as_nanos(), actual),
+        }
+    }
+
+    pub fn factor(&self) -> f64 {
+        let (numer, denom) = self.ratio();
+        numer as f64 / denom as f64
+    }
+
+    #[cfg(feature = "rug")]
     pub fn rational(&self) -> Rational {
         Rational::from((
             Integer::from(self.expected_duration().as_nanos()),
@@ -36,57 +51,83 @@ pub trait Perfect {
     fn perfect(&self, perfecter: &Perfecter) -> Self;
 }
 
+fn scale(value: u128, (numer, denom): (u128, u128)) -> Option<u128> {
+    Some(value.checked_mul(numer)?.checked_add(denom / 2)? / denom)
+}
+
+macro_rules! def_uint_conv {
+    ( $($t:ty),* ) => {
+        $(
+            impl Perfect for $t {
+                fn perfect(&self, perfecter: &Perfecter) -> Self {
+                    match scale(*self as u128, perfecter.ratio()) {
+                        Some(value) => <$t>::try_from(value).unwrap_or(<$t>::MAX),
+                        None => (*self as f64 * perfecter.factor()) as $t,
+                    }
+                }
+            }
+        )*
+    };
+}
+
 macro_rules! def_int_conv {
-    ( $t:ty, $i:ident ) => {
-        impl Perfect for $t {
-            fn perfect(&self, perfecter: &Perfecter) -> Self {
-                let (n, d) = (perfecter.rational() * Rational::from((*self, 1))).into_numer_denom();
-                let n = Float::with_val(52, n.$i().unwrap());
-                let d = Float::with_val(52, d.$i().unwrap());
-                (n / d).to_integer().unwrap().$i().unwrap()
+    ( $($t:ty),* ) => {
+        $(
+            impl Perfect for $t {
+                fn perfect(&self, perfecter: &Perfecter) -> Self {
+                    let value = match scale(self.unsigned_abs() as u128, perfecter.ratio()) {
+                        Some(value) => i128::try_from(value)
+                            .ok()
+                            .and_then(|value| {
+                                <$t>::try_from(if *self < 0 { -value } else { value }).ok()
+                            }),
+                        None => None,
+                    };
+
+                    value.unwrap_or_else(|| (*self as f64 * perfecter.factor()) as $t)
+                }
             }
-        }
+        )*
     };
 }
 
-def_int_conv!(isize, to_isize);
-def_int_conv!(usize, to_usize);
-def_int_conv!(i8, to_i8);
-def_int_conv!(u8, to_u8);
-def_int_conv!(i16, to_i16);
-def_int_conv!(u16, to_u16);
-def_int_conv!(i32, to_i32);
-def_int_conv!(u32, to_u32);
-def_int_conv!(i64, to_i64);
-def_int_conv!(u64, to_u64);
-def_int_conv!(i128, to_i128);
-def_int_conv!(u128, to_u128);
+def_uint_conv!(usize, u8, u16, u32, u64, u128);
+def_int_conv!(isize, i8, i16, i32, i64, i128);
 
 impl Perfect for f32 {
     fn perfect(&self, perfecter: &Perfecter) -> Self {
-        (Float::with_val(52, self) * perfecter.rational()).to_f32()
+        (*self as f64 * perfecter.factor()) as f32
     }
 }
 
 impl Perfect for f64 {
     fn perfect(&self, perfecter: &Perfecter) -> Self {
-        (Float::with_val(52, self) * perfecter.rational()).to_f64()
+        self * perfecter.factor()
     }
 }
 
 pub struct PerfecterProvider {
-    expected_duration: Duration,
+    expected_duration: RwLock<Duration>,
     prev: RwLock<Option<Instant>>,
 }
 
 impl PerfecterProvider {
     pub fn new(duration: Duration) -> PerfecterProvider {
         PerfecterProvider {
-            expected_duration: duration,
+            expected_duration: RwLock::new(duration),
             prev: RwLock::new(None),
         }
     }
 
+    pub fn expected_duration(&self) -> Duration {
+        *(self.expected_duration.read().unwrap())
+    }
+
+    pub fn set_expected_duration(&self, duration: Duration) {
+        let mut d = self.expected_duration.write().unwrap();
+        *d = duration;
+    }
+
     fn get_prev(&self) -> Option<Instant> {
         *(self.prev.read().unwrap())
     }
@@ -102,7 +143,7 @@ impl PerfecterProvider {
                 let now = Instant::now();
                 let dur = now - prev;
                 let res = Perfecter {
-                    expected_duration: self.expected_duration,
+                    expected_duration: self.expected_duration(),
                     actual_duration: dur,
                 };
                 self.set_prev(Some(now));
@@ -118,3 +159,34 @@ impl PerfecterProvider {
 
 unsafe impl Send for PerfecterProvider {}
 unsafe impl Sync for PerfecterProvider {}
+
+#[cfg(test)]
+mod test {
+    use super::Perfecter;
+    use std::time::Duration;
+
+    fn perfecter(expected: u64, actual: u64) -> Perfecter {
+        Perfecter {
+            expected_duration: Duration::from_millis(expected),
+            actual_duration: Duration::from_millis(actual),
+        }
+    }
+
+    #[test]
+    fn perfect_ints() {
+        let p = perfecter(1000, 1500);
+        assert_eq!(p.perfect(&300u64), 200);
+        assert_eq!(p.perfect(&-300i32), -200);
+        assert_eq!(p.perfect(&1u8), 1);
+        assert_eq!(p.perfect(&1.5f64), 1.0);
+
+        let p = perfecter(2000, 1000);
+        assert_eq!(p.perfect(&200u8), u8::MAX);
+        assert_eq!(p.perfect(&-100i8), i8::MIN);
+        assert_eq!(p.perfect(&u128::MAX), u128::MAX);
+        assert_eq!(p.perfect(&i128::MIN), i128::MIN);
+
+        let p = perfecter(1000, 0);
+        assert_eq!(p.perfect(&42u32), 42);
+    }
+}
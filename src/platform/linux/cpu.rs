@@ -0,0 +1,65 @@
+use crate::types::cpu::{CoreLoadInfo, CoresLoadInfo};
+use crate::types::{Error, Result};
+
+use super::procfs::read_string;
+
+pub fn parse_stat(content: &str) -> CoresLoadInfo {
+    content
+        .lines()
+        .filter(|line| {
+            line.starts_with("cpu") && line.as_bytes().get(3).is_some_and(u8::is_ascii_digit)
+        })
+        .filter_map(|line| {
+            let ticks = line
+                .split_whitespace()
+                .skip(1)
+                .map(|v| v.parse().ok())
+                .collect::<Option<Vec<usize>>>()?;
+            if ticks.len() < 4 {
+                return None;
+            }
+            let tick = |i: usize| ticks.get(i).copied().unwrap_or(0);
+
+            Some(CoreLoadInfo {
+                system: tick(2) + tick(5) + tick(6),
+                user: tick(0) + tick(1),
+                idle: tick(3) + tick(4),
+            })
+        })
+        .collect()
+}
+
+pub fn load() -> Result<CoresLoadInfo> {
+    let stat =
+        read_string("/proc/stat").ok_or_else(|| Error::not_found("Cannot read /proc/stat"))?;
+
+    match parse_stat(&stat) {
+        cores if cores.is_empty() => Err(Error::parse("/proc/stat", None)),
+        cores => Ok(cores),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_stat;
+
+    #[test]
+    fn cores() {
+        let cores = parse_stat(
+            "cpu  30 2 20 400 10 3 4 0 0 0\n\
+             cpu0 10 1 5 200 5 1 2 0 0 0\n\
+             cpu1 20 1 15 200 5 2 2 0 0 0\n\
+             intr 12345 0 0\n",
+        );
+
+        assert_eq!(cores.len(), 2);
+        assert_eq!(
+            (cores[0].user, cores[0].system, cores[0].idle),
+            (11, 8, 205)
+        );
+        assert_eq!(
+            (cores[1].user, cores[1].system, cores[1].idle),
+            (21, 19, 205)
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::platform::unix::resolv::{self, parse_resolv_conf};
+use crate::types::network::Dns;
+use crate::types::Result;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr};
+
+use super::procfs::{read_string, read_value};
+
+const RESOLVED_CONF: &str = "/run/systemd/resolve/resolv.conf";
+const RESOLVED_NETIF: &str = "/run/systemd/resolve/netif";
+
+fn is_resolved_stub(ip: &IpAddr) -> bool {
+    *ip == IpAddr::V4(Ipv4Addr::new(127, 0, 0, 53))
+        || *ip == IpAddr::V4(Ipv4Addr::new(127, 0, 0, 54))
+}
+
+fn interface_names() -> HashMap<u32, String> {
+    std::fs::read_dir("/sys/class/net")
+        .map(|dir| {
+            dir.filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let index = read_value(entry.path().join("ifindex"))?;
+                    Some((index, entry.file_name().to_string_lossy().into_owned()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn resolved_interfaces() -> HashMap<String, Vec<IpAddr>> {
+    let names = interface_names();
+    let mut res = HashMap::new();
+
+    let entries = match std::fs::read_dir(RESOLVED_NETIF) {
+        Ok(entries) => entries,
+        Err(_) => return res,
+    };
+
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let name = match entry
+            .file_name()
+            .to_str()
+            .and_then(|index| index.parse::<u32>().ok())
+            .and_then(|index| names.get(&index))
+        {
+            Some(name) => name.clone(),
+            None => continue,
+        };
+
+        let servers = read_string(entry.path())
+            .and_then(|content| {
+                content
+                    .lines()
+                    .find(|line| line.starts_with("SERVERS="))
+                    .map(|line| {
+                        line["SERVERS=".len()..]
+                            .split_whitespace()
+                            .filter_map(|ip| ip.split(['%', '#']).next())
+                            .filter_map(|ip| ip.parse().ok())
+                            .collect::<Vec<_>>()
+                    })
+            })
+            .unwrap_or_default();
+
+        if !servers.is_empty() {
+            res.insert(name, servers);
+        }
+    }
+
+    res
+}
+
+pub fn dns() -> Result<Dns> {
+    let mut res = resolv::dns()?;
+
+    if res.nameservers.iter().any(is_resolved_stub) {
+        res.resolved_stub = true;
+        if let Some(content) = read_string(RESOLVED_CONF) {
+            res.nameservers = parse_resolv_conf(&content).nameservers;
+        }
+        res.interfaces = resolved_interfaces();
+    }
+
+    Ok(res)
+}
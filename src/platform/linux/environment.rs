@@ -0,0 +1,197 @@
+use crate::types::misc::{Container, Environment, Hypervisor};
+use std::path::Path;
+
+use super::procfs::read_string;
+
+const DMI_FILES: [&str; 4] = [
+    "/sys/class/dmi/id/product_name",
+    "/sys/class/dmi/id/sys_vendor",
+    "/sys/class/dmi/id/board_vendor",
+    "/sys/class/dmi/id/bios_vendor",
+];
+
+pub fn hypervisor_from_dmi(value: &str) -> Option<Hypervisor> {
+    let value = value.trim().to_lowercase();
+
+    if value.starts_with("kvm") || value.starts_with("openstack") || value.starts_with("kubevirt") {
+        Some(Hypervisor::KVM)
+    } else if value.starts_with("qemu") {
+        Some(Hypervisor::QEMU)
+    } else if value.starts_with("vmware") || value.starts_with("vmw") {
+        Some(Hypervisor::VMware)
+    } else if value.starts_with("innotek gmbh") || value.starts_with("virtualbox") {
+        Some(Hypervisor::VirtualBox)
+    } else if value.starts_with("xen") {
+        Some(Hypervisor::Xen)
+    } else if value.starts_with("parallels") {
+        Some(Hypervisor::Parallels)
+    } else if value == "virtual machine" || value.starts_with("hyper-v") {
+        Some(Hypervisor::HyperV)
+    } else if value.starts_with("bochs") {
+        Some(Hypervisor::Other("Bochs".to_string()))
+    } else if value.starts_with("bhyve") {
+        Some(Hypervisor::Other("bhyve".to_string()))
+    } else {
+        None
+    }
+}
+
+pub fn cloud_from_dmi(value: &str) -> Option<Hypervisor> {
+    match value.trim().to_lowercase().as_str() {
+        "amazon ec2" => Some(Hypervisor::Other("Amazon EC2".to_string())),
+        "google compute engine" => Some(Hypervisor::Other("Google Compute Engine".to_string())),
+        _ => None,
+    }
+}
+
+fn has_hypervisor_flag() -> bool {
+    read_string("/proc/cpuinfo")
+        .map(|cpuinfo| {
+            cpuinfo
+                .lines()
+                .filter(|line| line.starts_with("flags"))
+                .any(|line| line.split_whitespace().any(|flag| flag == "hypervisor"))
+        })
+        .unwrap_or(false)
+}
+
+fn hypervisor() -> Option<Hypervisor> {
+    if let Some(hypervisor) = DMI_FILES
+        .iter()
+        .filter_map(read_string)
+        .filter_map(|value| hypervisor_from_dmi(&value))
+        .next()
+    {
+        return Some(hypervisor);
+    }
+
+    if read_string("/sys/hypervisor/type")
+        .as_deref()
+        .map(str::trim)
+        == Some("xen")
+    {
+        return Some(Hypervisor::Xen);
+    }
+
+    if has_hypervisor_flag() {
+        DMI_FILES
+            .iter()
+            .filter_map(read_string)
+            .find_map(|value| cloud_from_dmi(&value))
+            .or(Some(Hypervisor::Unknown))
+    } else {
+        None
+    }
+}
+
+fn container_from_marker(value: &str) -> Option<Container> {
+    match value.trim() {
+        "" => None,
+        "docker" => Some(Container::Docker),
+        "podman" | "oci" => Some(Container::Podman),
+        "lxc" | "lxc-libvirt" => Some(Container::LXC),
+        other => Some(Container::Other(other.to_string())),
+    }
+}
+
+pub fn container_from_cgroup(cgroup: &str) -> Option<Container> {
+    let paths = cgroup
+        .lines()
+        .filter_map(|line| line.splitn(3, ':').nth(2))
+        .collect::<Vec<_>>();
+
+    if paths.iter().any(|path| path.contains("kubepods")) {
+        Some(Container::Kubernetes)
+    } else if paths.iter().any(|path| path.contains("libpod")) {
+        Some(Container::Podman)
+    } else if paths.iter().any(|path| path.contains("docker")) {
+        Some(Container::Docker)
+    } else if paths.iter().any(|path| path.contains("lxc")) {
+        Some(Container::LXC)
+    } else {
+        None
+    }
+}
+
+fn init_environ(key: &str) -> Option<String> {
+    let environ = std::fs::read("/proc/1/environ").ok()?;
+    let prefix = format!("{}=", key);
+
+    environ
+        .split(|b| *b == 0)
+        .map(String::from_utf8_lossy)
+        .find(|var| var.starts_with(&prefix))
+        .map(|var| var[prefix.len()..].to_string())
+}
+
+fn container() -> Option<Container> {
+    let cgroup = read_string("/proc/1/cgroup")
+        .as_deref()
+        .and_then(container_from_cgroup);
+
+    if std::env::var_os("KUBERNETES_SERVICE_HOST").is_some()
+        || cgroup == Some(Container::Kubernetes)
+    {
+        return Some(Container::Kubernetes);
+    }
+
+    if let Some(container) = std::env::var("container")
+        .ok()
+        .or_else(|| init_environ("container"))
+        .as_deref()
+        .and_then(container_from_marker)
+    {
+        return Some(container);
+    }
+
+    if Path::new("/run/.containerenv").exists() {
+        Some(Container::Podman)
+    } else if Path::new("/.dockerenv").exists() {
+        Some(Container::Docker)
+    } else {
+        cgroup
+    }
+}
+
+pub fn get() -> Environment {
+    Environment {
+        hypervisor: hypervisor(),
+        container: container(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{cloud_from_dmi, container_from_cgroup, hypervisor_from_dmi};
+    use crate::types::misc::{Container, Hypervisor};
+
+    #[test]
+    fn cgroup_markers() {
+        assert_eq!(
+            container_from_cgroup("12:cpu,cpuacct:/docker/3f2a9c\n0::/docker/3f2a9c\n"),
+            Some(Container::Docker)
+        );
+        assert_eq!(
+            container_from_cgroup("0::/kubepods/besteffort/pod1234/3f2a9c\n"),
+            Some(Container::Kubernetes)
+        );
+        assert_eq!(container_from_cgroup("0::/init.scope\n"), None);
+    }
+
+    #[test]
+    fn dmi_vendors() {
+        assert_eq!(hypervisor_from_dmi("QEMU\n"), Some(Hypervisor::QEMU));
+        assert_eq!(
+            hypervisor_from_dmi("Virtual Machine"),
+            Some(Hypervisor::HyperV)
+        );
+        assert_eq!(hypervisor_from_dmi("Dell Inc."), None);
+        assert_eq!(hypervisor_from_dmi("Google"), None);
+        assert_eq!(hypervisor_from_dmi("Amazon EC2"), None);
+        assert_eq!(cloud_from_dmi("Google"), None);
+        assert_eq!(
+            cloud_from_dmi("Google Compute Engine\n"),
+            Some(Hypervisor::Other("Google Compute Engine".to_string()))
+        );
+    }
+}
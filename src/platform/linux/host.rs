@@ -0,0 +1,82 @@
+use crate::platform::unix::host::{domainname, get_uname, timezone};
+use crate::types::{misc::Host, Result};
+use std::collections::HashMap;
+
+use super::procfs::read_string;
+
+pub fn parse_os_release(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next()?.trim();
+            let value = parts.next()?.trim();
+            let value = if value.len() >= 2
+                && (value.starts_with('"') && value.ends_with('"')
+                    || value.starts_with('\'') && value.ends_with('\''))
+            {
+                &value[1..value.len() - 1]
+            } else {
+                value
+            };
+
+            Some((key.to_string(), value.replace("\\\"", "\"")))
+        })
+        .collect()
+}
+
+fn machine_id() -> Option<String> {
+    read_string("/etc/machine-id")
+        .or_else(|| read_string("/var/lib/dbus/machine-id"))
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+}
+
+fn linux_timezone() -> Option<String> {
+    timezone().or_else(|| {
+        read_string("/etc/timezone")
+            .map(|tz| tz.trim().to_string())
+            .filter(|tz| !tz.is_empty())
+    })
+}
+
+pub fn get() -> Result<Host> {
+    let uname = get_uname()?;
+    let mut release = read_string("/etc/os-release")
+        .or_else(|| read_string("/usr/lib/os-release"))
+        .map(|content| parse_os_release(&content))
+        .unwrap_or_default();
+
+    Ok(Host {
+        hostname: uname.nodename,
+        domainname: domainname(),
+        os_name: release.remove("NAME").unwrap_or(uname.sysname),
+        os_version: release
+            .remove("VERSION_ID")
+            .or_else(|| release.remove("VERSION")),
+        os_id: release.remove("ID"),
+        kernel: uname.release,
+        arch: uname.machine,
+        machine_id: machine_id(),
+        timezone: linux_timezone(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_os_release;
+
+    #[test]
+    fn os_release() {
+        let release = parse_os_release(
+            "NAME=\"Debian GNU/Linux\"\nVERSION_ID=\"12\"\n# comment\nID=debian\nPRETTY_NAME='Debian 12'\n",
+        );
+
+        assert_eq!(release["NAME"], "Debian GNU/Linux");
+        assert_eq!(release["VERSION_ID"], "12");
+        assert_eq!(release["ID"], "debian");
+        assert_eq!(release["PRETTY_NAME"], "Debian 12");
+    }
+}
@@ -0,0 +1,106 @@
+use crate::types::kernel::{Irq, KernelActivity};
+use crate::types::{Error, Result};
+
+use super::procfs::read_string;
+
+pub fn parse_stat(content: &str) -> KernelActivity {
+    let mut res = KernelActivity {
+        context_switches: 0,
+        interrupts: 0,
+        soft_interrupts: 0,
+        forks: 0,
+        procs_running: 0,
+        procs_blocked: 0,
+        irqs: Vec::new(),
+    };
+
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let key = match fields.next() {
+            Some(key) => key,
+            None => continue,
+        };
+        let value = match fields.next().and_then(|v| v.parse::<u64>().ok()) {
+            Some(value) => value,
+            None => continue,
+        };
+
+        match key {
+            "ctxt" => res.context_switches = value,
+            "intr" => res.interrupts = value,
+            "softirq" => res.soft_interrupts = value,
+            "processes" => res.forks = value,
+            "procs_running" => res.procs_running = value,
+            "procs_blocked" => res.procs_blocked = value,
+            _ => {}
+        }
+    }
+
+    res
+}
+
+pub fn parse_interrupts(content: &str) -> Vec<Irq> {
+    let mut lines = content.lines();
+    let cpus = match lines.next() {
+        Some(header) => header.split_whitespace().count(),
+        None => return Vec::new(),
+    };
+
+    lines
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, ':');
+            let name = parts.next()?.trim().to_string();
+            let rest = parts.next()?;
+            let mut tokens = rest.split_whitespace().peekable();
+            let mut per_cpu = Vec::with_capacity(cpus);
+
+            while per_cpu.len() < cpus {
+                match tokens.peek().and_then(|t| t.parse::<u64>().ok()) {
+                    Some(count) => {
+                        per_cpu.push(count);
+                        tokens.next();
+                    }
+                    None => break,
+                }
+            }
+
+            Some(Irq {
+                name,
+                description: tokens.collect::<Vec<_>>().join(" "),
+                per_cpu,
+            })
+        })
+        .collect()
+}
+
+pub fn activity() -> Result<KernelActivity> {
+    let stat =
+        read_string("/proc/stat").ok_or_else(|| Error::not_found("Cannot read /proc/stat"))?;
+    let mut res = parse_stat(&stat);
+
+    if let Some(interrupts) = read_string("/proc/interrupts") {
+        res.irqs = parse_interrupts(&interrupts);
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_interrupts;
+
+    #[test]
+    fn interrupts() {
+        let irqs = parse_interrupts(
+            "           CPU0       CPU1\n  0:         44          3   IO-APIC   2-edge      timer\nNMI:          0          1   Non-maskable interrupts\nERR:          0\n",
+        );
+
+        assert_eq!(irqs.len(), 3);
+        assert_eq!(irqs[0].name, "0");
+        assert_eq!(irqs[0].per_cpu, vec![44, 3]);
+        assert_eq!(irqs[0].description, "IO-APIC 2-edge timer");
+        assert_eq!(irqs[0].total(), 47);
+        assert_eq!(irqs[1].description, "Non-maskable interrupts");
+        assert_eq!(irqs[2].per_cpu, vec![0]);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::types::limits::{Limit, Limits};
+use crate::types::{Error, Result};
+
+use super::procfs::{read_string, read_value, read_values};
+
+fn missing(path: &str) -> Error {
+    Error::not_found(format!("Cannot read {}", path))
+}
+
+fn thread_count() -> Option<u64> {
+    read_string("/proc/loadavg")?
+        .split_whitespace()
+        .nth(3)?
+        .split('/')
+        .nth(1)?
+        .parse()
+        .ok()
+}
+
+pub fn get() -> Result<Limits> {
+    let files: Vec<u64> =
+        read_values("/proc/sys/fs/file-nr").ok_or_else(|| missing("/proc/sys/fs/file-nr"))?;
+    let inodes: Vec<u64> =
+        read_values("/proc/sys/fs/inode-nr").ok_or_else(|| missing("/proc/sys/fs/inode-nr"))?;
+    if files.len() < 3 {
+        return Err(Error::parse("/proc/sys/fs/file-nr", Some(1)));
+    }
+    if inodes.len() < 2 {
+        return Err(Error::parse("/proc/sys/fs/inode-nr", Some(1)));
+    }
+    let tasks = thread_count().ok_or_else(|| missing("/proc/loadavg"))?;
+
+    Ok(Limits {
+        files: Limit {
+            used: files[0].saturating_sub(files[1]),
+            max: files[2],
+        },
+        inodes_allocated: inodes[0],
+        inodes_free: inodes[1],
+        pids: Limit {
+            used: tasks,
+            max: read_value("/proc/sys/kernel/pid_max")
+                .ok_or_else(|| missing("/proc/sys/kernel/pid_max"))?,
+        },
+        threads: Limit {
+            used: tasks,
+            max: read_value("/proc/sys/kernel/threads-max")
+                .ok_or_else(|| missing("/proc/sys/kernel/threads-max"))?,
+        },
+        entropy: Limit {
+            used: read_value("/proc/sys/kernel/random/entropy_avail").unwrap_or(0),
+            max: read_value("/proc/sys/kernel/random/poolsize").unwrap_or(0),
+        },
+    })
+}
@@ -0,0 +1,80 @@
+use crate::types::mem::{Swap, RAM};
+use crate::types::{Error, Result};
+use std::collections::HashMap;
+
+use super::procfs::read_string;
+
+pub fn parse_meminfo(content: &str) -> HashMap<&str, u64> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, ':');
+            let key = parts.next()?.trim();
+            let mut value = parts.next()?.split_whitespace();
+            let amount: u64 = value.next()?.parse().ok()?;
+
+            match value.next() {
+                Some("kB") => Some((key, amount * 1024)),
+                None => Some((key, amount)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn meminfo<F, T>(f: F) -> Result<T>
+where
+    F: FnOnce(&HashMap<&str, u64>) -> Option<T>,
+{
+    let content = read_string("/proc/meminfo")
+        .ok_or_else(|| Error::not_found("Cannot read /proc/meminfo"))?;
+
+    f(&parse_meminfo(&content)).ok_or_else(|| Error::parse("/proc/meminfo", None))
+}
+
+pub fn ram() -> Result<RAM> {
+    meminfo(|info| {
+        let total = *info.get("MemTotal")?;
+        let available = match info.get("MemAvailable") {
+            Some(available) => *available,
+            None => info.get("MemFree")? + info.get("Buffers")? + info.get("Cached")?,
+        };
+
+        Some(RAM {
+            used: total.saturating_sub(available),
+            total,
+        })
+    })
+}
+
+pub fn swap() -> Result<Swap> {
+    meminfo(|info| {
+        let total = *info.get("SwapTotal")?;
+        let free = *info.get("SwapFree")?;
+
+        Some(Swap {
+            used: total.saturating_sub(free),
+            free,
+            total,
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_meminfo;
+
+    #[test]
+    fn meminfo() {
+        let info = parse_meminfo(
+            "MemTotal:       16000000 kB\n\
+             MemFree:         1000000 kB\n\
+             MemAvailable:    8000000 kB\n\
+             HugePages_Total:       0\n",
+        );
+
+        assert_eq!(info["MemTotal"], 16000000 * 1024);
+        assert_eq!(info["MemAvailable"], 8000000 * 1024);
+        assert_eq!(info["HugePages_Total"], 0);
+    }
+}
@@ -0,0 +1,105 @@
+pub mod cpu;
+pub mod dns;
+pub mod environment;
+pub mod host;
+pub mod kernel;
+pub mod limits;
+pub mod mem;
+pub mod netif;
+pub mod procfs;
+pub mod protocols;
+pub mod routes;
+pub mod scan;
+pub mod sockets;
+pub mod topology;
+pub mod users;
+pub mod wireless;
+
+pub mod prelude {
+    pub use crate::types::{fs::FileSystem, power::PowerSources, thermal::Fans, Error, Result};
+    pub use std::time::SystemTime;
+}
+
+pub mod imp {
+    pub mod network {
+        pub use super::super::dns::dns;
+        pub use super::super::netif::all;
+        pub use super::super::routes::routes;
+        pub use super::super::topology::topology;
+    }
+
+    pub mod cpu {
+        pub use super::super::cpu::load;
+        pub use crate::platform::unix::cpu::loadavg;
+    }
+
+    pub mod fs {
+        use crate::platform::linux::prelude::*;
+        pub fn all() -> Result<Vec<FileSystem>> {
+            Err(Error::unsupported("Filesystems unsupported"))
+        }
+    }
+
+    pub mod mem {
+        pub use super::super::mem::{ram, swap};
+    }
+
+    pub mod power {
+        use crate::platform::linux::prelude::*;
+        pub fn sources() -> Result<PowerSources> {
+            Err(Error::unsupported("Power sources unsupported"))
+        }
+    }
+
+    pub mod thermal {
+        use crate::platform::linux::prelude::*;
+        pub fn cpus() -> Result<Vec<f64>> {
+            Err(Error::unsupported("CPU temperature sensors unsupported"))
+        }
+        pub fn custom(_key: &str) -> Result<f64> {
+            Err(Error::unsupported("Custom sensors unsupported"))
+        }
+        pub fn fans() -> Result<Fans> {
+            Err(Error::unsupported("Fan sensors unsupported"))
+        }
+    }
+
+    pub mod boottime {
+        use crate::platform::linux::prelude::*;
+        pub fn get() -> Result<SystemTime> {
+            Err(Error::unsupported("Boot time unsupported"))
+        }
+    }
+
+    pub mod environment {
+        pub use super::super::environment::get;
+    }
+
+    pub mod host {
+        pub use super::super::host::get;
+    }
+
+    pub mod users {
+        pub use super::super::users::sessions;
+    }
+
+    pub mod kernel {
+        pub use super::super::kernel::activity;
+    }
+
+    pub mod limits {
+        pub use super::super::limits::get;
+    }
+
+    pub mod sockets {
+        pub use super::super::sockets::{all, tcp_summary};
+    }
+
+    pub mod protocols {
+        pub use super::super::protocols::counters;
+    }
+
+    pub mod wifi {
+        pub use super::super::scan::scan;
+    }
+}
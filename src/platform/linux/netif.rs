@@ -0,0 +1,294 @@
+use crate::platform::unix::ifaddrs;
+use crate::types::network::{Duplex, InterfaceStats, NetworkInterface, Type};
+use crate::types::{Error, Result};
+use std::path::Path;
+
+use super::procfs::{read_string, read_value};
+use super::wireless;
+
+const SYS_CLASS_NET: &str = "/sys/class/net";
+
+const ARPHRD_ETHER: u32 = 1;
+const ARPHRD_PPP: u32 = 512;
+const ARPHRD_RAWIP: u32 = 519;
+const ARPHRD_TUNNEL: u32 = 768;
+const ARPHRD_TUNNEL6: u32 = 769;
+const ARPHRD_LOOPBACK: u32 = 772;
+const ARPHRD_SIT: u32 = 776;
+const ARPHRD_IPGRE: u32 = 778;
+const ARPHRD_IEEE80211: u32 = 801;
+const ARPHRD_IEEE80211_RADIOTAP: u32 = 803;
+const ARPHRD_IP6GRE: u32 = 823;
+const ARPHRD_NONE: u32 = 65534;
+
+fn statistic(iface: &Path, name: &str) -> u64 {
+    read_value(iface.join("statistics").join(name)).unwrap_or(0)
+}
+
+fn stats(iface: &Path) -> InterfaceStats {
+    InterfaceStats {
+        rx_packets: statistic(iface, "rx_packets"),
+        tx_packets: statistic(iface, "tx_packets"),
+        rx_errors: statistic(iface, "rx_errors"),
+        tx_errors: statistic(iface, "tx_errors"),
+        rx_dropped: statistic(iface, "rx_dropped"),
+        tx_dropped: statistic(iface, "tx_dropped"),
+        collisions: statistic(iface, "collisions"),
+        rx_multicast: statistic(iface, "multicast"),
+        tx_multicast: 0,
+    }
+}
+
+fn devtype(iface: &Path) -> Option<String> {
+    read_string(iface.join("uevent"))?
+        .lines()
+        .find(|line| line.starts_with("DEVTYPE="))
+        .map(|line| line["DEVTYPE=".len()..].trim().to_string())
+}
+
+#[derive(Default)]
+struct Markers<'a> {
+    arphrd: Option<u32>,
+    devtype: Option<&'a str>,
+    wireless: bool,
+    bridge: bool,
+    bonding: bool,
+    tun: bool,
+    is_virtual: bool,
+}
+
+fn classify(markers: &Markers) -> Type {
+    let Markers {
+        arphrd, devtype, ..
+    } = *markers;
+
+    if arphrd == Some(ARPHRD_LOOPBACK) {
+        Type::Loopback
+    } else if markers.wireless
+        || devtype == Some("wlan")
+        || arphrd.is_some_and(|t| (ARPHRD_IEEE80211..=ARPHRD_IEEE80211_RADIOTAP).contains(&t))
+    {
+        Type::WiFi
+    } else if markers.bridge || devtype == Some("bridge") {
+        Type::Bridge
+    } else if markers.bonding || devtype == Some("bond") {
+        Type::Bond
+    } else if devtype == Some("vlan") {
+        Type::Vlan
+    } else if markers.tun
+        || matches!(
+            devtype,
+            Some("wireguard")
+                | Some("gre")
+                | Some("ipip")
+                | Some("sit")
+                | Some("vxlan")
+                | Some("geneve")
+        )
+        || matches!(
+            arphrd,
+            Some(ARPHRD_PPP)
+                | Some(ARPHRD_TUNNEL)
+                | Some(ARPHRD_TUNNEL6)
+                | Some(ARPHRD_SIT)
+                | Some(ARPHRD_IPGRE)
+                | Some(ARPHRD_IP6GRE)
+                | Some(ARPHRD_NONE)
+        )
+    {
+        Type::Tunnel
+    } else if devtype == Some("wwan") || arphrd == Some(ARPHRD_RAWIP) {
+        Type::Cellular
+    } else if arphrd == Some(ARPHRD_ETHER) {
+        if markers.is_virtual {
+            Type::Virtual
+        } else {
+            Type::Wired
+        }
+    } else {
+        Type::Other(match (devtype, arphrd) {
+            (Some(devtype), _) => devtype.to_string(),
+            (None, Some(arphrd)) => format!("arphrd {}", arphrd),
+            (None, None) => "unknown".to_string(),
+        })
+    }
+}
+
+fn itype(iface: &Path) -> Type {
+    let devtype = devtype(iface);
+
+    classify(&Markers {
+        arphrd: read_value(iface.join("type")),
+        devtype: devtype.as_deref(),
+        wireless: iface.join("wireless").exists() || iface.join("phy80211").exists(),
+        bridge: iface.join("bridge").exists(),
+        bonding: iface.join("bonding").exists(),
+        tun: iface.join("tun_flags").exists(),
+        is_virtual: iface
+            .canonicalize()
+            .is_ok_and(|path| path.starts_with("/sys/devices/virtual")),
+    })
+}
+
+fn is_up(iface: &Path) -> bool {
+    read_string(iface.join("flags"))
+        .and_then(|flags| u32::from_str_radix(flags.trim().trim_start_matches("0x"), 16).ok())
+        .is_some_and(|flags| flags & (libc::IFF_UP as u32) != 0)
+}
+
+fn speed(iface: &Path) -> Option<u64> {
+    let speed: i64 = read_value(iface.join("speed"))?;
+
+    if speed > 0 {
+        Some(speed as u64)
+    } else {
+        None
+    }
+}
+
+fn duplex(iface: &Path) -> Option<Duplex> {
+    match read_string(iface.join("duplex"))?.trim() {
+        "full" => Some(Duplex::Full),
+        "half" => Some(Duplex::Half),
+        _ => None,
+    }
+}
+
+fn carrier(iface: &Path) -> Option<bool> {
+    read_value::<u8, _>(iface.join("carrier")).map(|carrier| carrier != 0)
+}
+
+pub fn all() -> Result<Vec<NetworkInterface>> {
+    let mut addrs = ifaddrs::all()?;
+    let mut wireless = wireless::all();
+    let mut res = Vec::new();
+
+    for entry in std::fs::read_dir(SYS_CLASS_NET).map_err(Error::from)? {
+        let entry = entry.map_err(Error::from)?;
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let addr = addrs.remove(&name).unwrap_or_default();
+        let itype = itype(&path);
+        let wireless = if itype == Type::WiFi {
+            Some(
+                wireless
+                    .remove(&name)
+                    .unwrap_or_else(|| wireless::get(&name)),
+            )
+        } else {
+            None
+        };
+
+        let tx_bytes = statistic(&path, "tx_bytes");
+        let rx_bytes = statistic(&path, "rx_bytes");
+        let counters = stats(&path);
+
+        res.push(NetworkInterface {
+            itype,
+            is_up: is_up(&path),
+            display_name: None,
+            bssid: None,
+            ssid: None,
+            wireless,
+            up: tx_bytes,
+            down: rx_bytes,
+            stats: counters,
+            tx_bytes,
+            rx_bytes,
+            counters,
+            rates: None,
+            mac: addr.mac,
+            mtu: read_value(path.join("mtu")),
+            addresses: addr.addresses,
+            speed: speed(&path),
+            duplex: duplex(&path),
+            carrier: carrier(&path),
+            name,
+        });
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{classify, Markers, ARPHRD_ETHER, ARPHRD_LOOPBACK, ARPHRD_NONE, ARPHRD_RAWIP};
+    use crate::types::network::Type;
+
+    fn ether() -> Markers<'static> {
+        Markers {
+            arphrd: Some(ARPHRD_ETHER),
+            ..Markers::default()
+        }
+    }
+
+    #[test]
+    fn classify_interfaces() {
+        let lo = Markers {
+            arphrd: Some(ARPHRD_LOOPBACK),
+            is_virtual: true,
+            ..Markers::default()
+        };
+        assert_eq!(classify(&lo), Type::Loopback);
+
+        assert_eq!(classify(&ether()), Type::Wired);
+
+        let veth = Markers {
+            is_virtual: true,
+            ..ether()
+        };
+        assert_eq!(classify(&veth), Type::Virtual);
+
+        let bridge = Markers {
+            bridge: true,
+            is_virtual: true,
+            devtype: Some("bridge"),
+            ..ether()
+        };
+        assert_eq!(classify(&bridge), Type::Bridge);
+
+        let bond = Markers {
+            bonding: true,
+            is_virtual: true,
+            devtype: Some("bond"),
+            ..ether()
+        };
+        assert_eq!(classify(&bond), Type::Bond);
+
+        let vlan = Markers {
+            devtype: Some("vlan"),
+            is_virtual: true,
+            ..ether()
+        };
+        assert_eq!(classify(&vlan), Type::Vlan);
+
+        let tun = Markers {
+            arphrd: Some(ARPHRD_NONE),
+            tun: true,
+            is_virtual: true,
+            ..Markers::default()
+        };
+        assert_eq!(classify(&tun), Type::Tunnel);
+
+        let tap = Markers {
+            tun: true,
+            is_virtual: true,
+            ..ether()
+        };
+        assert_eq!(classify(&tap), Type::Tunnel);
+
+        let wwan = Markers {
+            arphrd: Some(ARPHRD_RAWIP),
+            devtype: Some("wwan"),
+            ..Markers::default()
+        };
+        assert_eq!(classify(&wwan), Type::Cellular);
+
+        let wlan = Markers {
+            wireless: true,
+            devtype: Some("wlan"),
+            ..ether()
+        };
+        assert_eq!(classify(&wlan), Type::WiFi);
+    }
+}
@@ -0,0 +1,20 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+pub fn read_string<P: AsRef<Path>>(path: P) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim_end_matches('\n').to_string())
+}
+
+pub fn read_value<T: FromStr, P: AsRef<Path>>(path: P) -> Option<T> {
+    read_string(path)?.trim().parse().ok()
+}
+
+pub fn read_values<T: FromStr, P: AsRef<Path>>(path: P) -> Option<Vec<T>> {
+    read_string(path)?
+        .split_whitespace()
+        .map(|v| v.parse().ok())
+        .collect()
+}
@@ -0,0 +1,118 @@
+use crate::types::protocols::{IpCounters, ProtocolCounters, TcpCounters, UdpCounters};
+use crate::types::{Error, Result};
+use std::collections::HashMap;
+
+use super::procfs::read_string;
+
+pub fn parse_snmp(content: &str) -> HashMap<String, u64> {
+    let mut res = HashMap::new();
+    let mut lines = content.lines().peekable();
+
+    while let Some(header) = lines.next() {
+        let mut header = header.split_whitespace();
+        let prefix = match header.next() {
+            Some(prefix) => prefix,
+            None => continue,
+        };
+        let values = match lines.peek() {
+            Some(line) if line.split_whitespace().next() == Some(prefix) => {
+                lines.next().unwrap_or_default().split_whitespace().skip(1)
+            }
+            _ => continue,
+        };
+        let prefix = prefix.trim_end_matches(':');
+
+        for (key, value) in header.zip(values) {
+            if let Ok(value) = value.parse::<i64>() {
+                res.insert(format!("{}.{}", prefix, key), value.max(0) as u64);
+            }
+        }
+    }
+
+    res
+}
+
+pub fn counters() -> Result<ProtocolCounters> {
+    let mut values = parse_snmp(
+        &read_string("/proc/net/snmp")
+            .ok_or_else(|| Error::not_found("Cannot read /proc/net/snmp"))?,
+    );
+    if let Some(netstat) = read_string("/proc/net/netstat") {
+        values.extend(parse_snmp(&netstat));
+    }
+    let get = |key: &str| values.get(key).copied().unwrap_or(0);
+
+    Ok(ProtocolCounters {
+        ip: IpCounters {
+            in_receives: get("Ip.InReceives"),
+            in_delivers: get("Ip.InDelivers"),
+            in_discards: get("Ip.InDiscards"),
+            in_hdr_errors: get("Ip.InHdrErrors"),
+            forwarded: get("Ip.ForwDatagrams"),
+            out_requests: get("Ip.OutRequests"),
+            out_discards: get("Ip.OutDiscards"),
+            out_no_routes: get("Ip.OutNoRoutes"),
+        },
+        tcp: TcpCounters {
+            active_opens: get("Tcp.ActiveOpens"),
+            passive_opens: get("Tcp.PassiveOpens"),
+            attempt_fails: get("Tcp.AttemptFails"),
+            estab_resets: get("Tcp.EstabResets"),
+            curr_estab: get("Tcp.CurrEstab"),
+            in_segs: get("Tcp.InSegs"),
+            out_segs: get("Tcp.OutSegs"),
+            retrans_segs: get("Tcp.RetransSegs"),
+            in_errs: get("Tcp.InErrs"),
+            out_rsts: get("Tcp.OutRsts"),
+            listen_overflows: get("TcpExt.ListenOverflows"),
+            listen_drops: get("TcpExt.ListenDrops"),
+        },
+        udp: UdpCounters {
+            in_datagrams: get("Udp.InDatagrams"),
+            out_datagrams: get("Udp.OutDatagrams"),
+            no_ports: get("Udp.NoPorts"),
+            in_errors: get("Udp.InErrors"),
+            rcvbuf_errors: get("Udp.RcvbufErrors"),
+            sndbuf_errors: get("Udp.SndbufErrors"),
+        },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_snmp;
+
+    #[test]
+    fn snmp_and_netstat() {
+        let snmp = parse_snmp(
+            "Ip: Forwarding DefaultTTL InReceives InHdrErrors InAddrErrors ForwDatagrams InUnknownProtos InDiscards InDelivers OutRequests OutDiscards OutNoRoutes\n\
+             Ip: 1 64 2279443 0 0 0 0 0 2279405 1983453 12 7\n\
+             IcmpMsg: InType3 OutType3\n\
+             IcmpMsg: 53 53\n\
+             Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens PassiveOpens AttemptFails EstabResets CurrEstab InSegs OutSegs RetransSegs InErrs OutRsts InCsumErrors\n\
+             Tcp: 1 200 120000 -1 17409 1023 1266 552 9 2226590 2100441 3012 2 4531 0\n\
+             Udp: InDatagrams NoPorts InErrors OutDatagrams RcvbufErrors SndbufErrors InCsumErrors IgnoredMulti MemErrors\n\
+             Udp: 48571 53 0 49113 0 0 0 1254 0\n",
+        );
+
+        assert_eq!(snmp["Ip.InReceives"], 2279443);
+        assert_eq!(snmp["Ip.OutNoRoutes"], 7);
+        assert_eq!(snmp["IcmpMsg.OutType3"], 53);
+        assert_eq!(snmp["Tcp.MaxConn"], 0);
+        assert_eq!(snmp["Tcp.RetransSegs"], 3012);
+        assert_eq!(snmp["Udp.NoPorts"], 53);
+        assert_eq!(snmp["Udp.IgnoredMulti"], 1254);
+
+        let netstat = parse_snmp(
+            "TcpExt: SyncookiesSent SyncookiesRecv ListenOverflows ListenDrops\n\
+             TcpExt: 0 0 17 21\n\
+             garbage\n\
+             IpExt: InNoRoutes InTruncatedPkts InOctets\n\
+             IpExt: 0 0 3405691582\n",
+        );
+
+        assert_eq!(netstat["TcpExt.ListenOverflows"], 17);
+        assert_eq!(netstat["TcpExt.ListenDrops"], 21);
+        assert_eq!(netstat["IpExt.InOctets"], 3405691582);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::types::network::Route;
+use crate::types::{Error, Result};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use super::procfs::read_string;
+
+fn parse_ipv4(hex: &str) -> Option<Ipv4Addr> {
+    let word = u32::from_str_radix(hex, 16).ok()?;
+    Some(Ipv4Addr::from(word.to_ne_bytes()))
+}
+
+fn parse_ipv6(hex: &str) -> Option<Ipv6Addr> {
+    u128::from_str_radix(hex, 16).ok().map(Ipv6Addr::from)
+}
+
+pub fn parse_route(content: &str) -> Vec<Route> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            if fields.len() < 8 {
+                return None;
+            }
+
+            let gateway = parse_ipv4(fields[2])?;
+            let mask = parse_ipv4(fields[7])?;
+
+            Some(Route {
+                destination: IpAddr::V4(parse_ipv4(fields[1])?),
+                prefix: u32::from(mask).count_ones() as u8,
+                gateway: if gateway.is_unspecified() {
+                    None
+                } else {
+                    Some(IpAddr::V4(gateway))
+                },
+                interface: fields[0].to_string(),
+                metric: fields[6].parse().ok()?,
+                flags: u32::from_str_radix(fields[3], 16).ok()?,
+            })
+        })
+        .collect()
+}
+
+pub fn parse_ipv6_route(content: &str) -> Vec<Route> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            if fields.len() < 10 {
+                return None;
+            }
+
+            let gateway = parse_ipv6(fields[4])?;
+
+            Some(Route {
+                destination: IpAddr::V6(parse_ipv6(fields[0])?),
+                prefix: u8::from_str_radix(fields[1], 16).ok()?,
+                gateway: if gateway.is_unspecified() {
+                    None
+                } else {
+                    Some(IpAddr::V6(gateway))
+                },
+                interface: fields[9].to_string(),
+                metric: u32::from_str_radix(fields[5], 16).ok()?,
+                flags: u32::from_str_radix(fields[8], 16).ok()?,
+            })
+        })
+        .collect()
+}
+
+pub fn routes() -> Result<Vec<Route>> {
+    let mut res = parse_route(
+        &read_string("/proc/net/route")
+            .ok_or_else(|| Error::not_found("Cannot read /proc/net/route"))?,
+    );
+
+    if let Some(content) = read_string("/proc/net/ipv6_route") {
+        res.append(&mut parse_ipv6_route(&content));
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_ipv6_route, parse_route};
+    use std::net::IpAddr;
+
+    #[test]
+    fn ipv4_routes() {
+        let gateway = u32::from_ne_bytes([192, 0, 2, 1]);
+        let network = u32::from_ne_bytes([192, 0, 2, 0]);
+        let mask = u32::from_ne_bytes([255, 255, 255, 0]);
+        let routes = parse_route(&format!(
+            "Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT\n\
+             eth0\t00000000\t{:08X}\t0003\t0\t0\t100\t00000000\t0\t0\t0\n\
+             eth0\t{:08X}\t00000000\t0001\t0\t0\t0\t{:08X}\t0\t0\t0\n",
+            gateway, network, mask
+        ));
+
+        assert_eq!(routes.len(), 2);
+        assert!(routes[0].is_default());
+        assert_eq!(
+            routes[0].gateway,
+            Some("192.0.2.1".parse::<IpAddr>().unwrap())
+        );
+        assert_eq!(routes[0].metric, 100);
+        assert_eq!(
+            routes[1].destination,
+            "192.0.2.0".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(routes[1].prefix, 24);
+        assert_eq!(routes[1].gateway, None);
+    }
+
+    #[test]
+    fn ipv6_routes() {
+        let routes = parse_ipv6_route(
+            "00000000000000000000000000000000 00 00000000000000000000000000000000 00 fd000000000000000000000000000001 00000400 00000001 00000000 00000003     eth0\n",
+        );
+
+        assert_eq!(routes.len(), 1);
+        assert!(routes[0].is_default());
+        assert_eq!(
+            routes[0].gateway,
+            Some("fd00::1".parse::<IpAddr>().unwrap())
+        );
+        assert_eq!(routes[0].metric, 1024);
+        assert_eq!(routes[0].interface, "eth0");
+    }
+}
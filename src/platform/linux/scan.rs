@@ -0,0 +1,285 @@
+use crate::types::wifi::{AccessPoint, Band, Security};
+use crate::types::{Error, ErrorKind, Result};
+use std::process::Command;
+
+use super::wireless::channel;
+
+const SYS_CLASS_NET: &str = "/sys/class/net";
+
+#[derive(Default)]
+struct Entry {
+    bssid: String,
+    associated: bool,
+    ssid: Option<String>,
+    frequency: Option<u32>,
+    channel: Option<u32>,
+    rssi: Option<i32>,
+    privacy: bool,
+    rsn: Option<Vec<String>>,
+    wpa: Option<Vec<String>>,
+}
+
+#[derive(Copy, Clone)]
+enum Block {
+    None,
+    Rsn,
+    Wpa,
+}
+
+fn security(entry: &Entry) -> Security {
+    let is_enterprise = |auths: &Vec<String>| auths.iter().any(|auth| auth.contains("802.1X"));
+
+    if let Some(auths) = &entry.rsn {
+        let is_wpa3 = auths
+            .iter()
+            .any(|auth| auth.contains("SAE") || auth.contains("SUITE-B"));
+
+        match (is_wpa3, is_enterprise(auths)) {
+            (true, true) => Security::WPA3Enterprise,
+            (true, false) => Security::WPA3Personal,
+            (false, true) => Security::WPA2Enterprise,
+            (false, false) => Security::WPA2Personal,
+        }
+    } else if let Some(auths) = &entry.wpa {
+        if is_enterprise(auths) {
+            Security::WPAEnterprise
+        } else {
+            Security::WPAPersonal
+        }
+    } else if entry.privacy {
+        Security::WEP
+    } else {
+        Security::Open
+    }
+}
+
+fn access_point(interface: &str, entry: Entry) -> AccessPoint {
+    AccessPoint {
+        interface: interface.to_string(),
+        security: security(&entry),
+        channel: entry.frequency.and_then(channel).or(entry.channel),
+        band: entry.frequency.and_then(Band::from_frequency),
+        ssid: entry.ssid,
+        bssid: entry.bssid,
+        frequency: entry.frequency,
+        rssi: entry.rssi,
+        associated: entry.associated,
+    }
+}
+
+fn value<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    line.strip_prefix(key)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .map(str::trim)
+}
+
+pub fn parse_scan_dump(interface: &str, content: &str) -> Vec<AccessPoint> {
+    let mut res = Vec::new();
+    let mut entry: Option<Entry> = None;
+    let mut block = Block::None;
+
+    for line in content.lines() {
+        if let Some(rest) = line.strip_prefix("BSS ") {
+            if let Some(entry) = entry.take() {
+                res.push(access_point(interface, entry));
+            }
+
+            entry = Some(Entry {
+                bssid: rest
+                    .split(|c: char| c == '(' || c.is_whitespace())
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+                associated: rest.contains("-- associated"),
+                ..Default::default()
+            });
+            block = Block::None;
+            continue;
+        }
+
+        let entry = match entry.as_mut() {
+            Some(entry) => entry,
+            None => continue,
+        };
+
+        if !line.starts_with("\t\t") {
+            block = Block::None;
+        }
+
+        let trimmed = line.trim();
+        let trimmed = if let Some(rest) = trimmed.strip_prefix("RSN:") {
+            block = Block::Rsn;
+            entry.rsn = Some(vec![]);
+            rest.trim()
+        } else if let Some(rest) = trimmed.strip_prefix("WPA:") {
+            block = Block::Wpa;
+            entry.wpa = Some(vec![]);
+            rest.trim()
+        } else {
+            trimmed
+        };
+        let trimmed = trimmed.trim_start_matches('*').trim();
+
+        if let Some(freq) = value(trimmed, "freq") {
+            entry.frequency = freq.parse::<f64>().ok().map(|f| f.round() as u32);
+        } else if let Some(signal) = value(trimmed, "signal") {
+            entry.rssi = signal
+                .split_whitespace()
+                .next()
+                .and_then(|s| s.parse::<f64>().ok())
+                .map(|s| s.round() as i32);
+        } else if let Some(ssid) = value(trimmed, "SSID") {
+            if !ssid.is_empty() {
+                entry.ssid = Some(ssid.to_string());
+            }
+        } else if let Some(caps) = value(trimmed, "capability") {
+            entry.privacy = caps.split_whitespace().any(|cap| cap == "Privacy");
+        } else if let Some(ds) = value(trimmed, "DS Parameter set") {
+            entry.channel = ds.trim_start_matches("channel").trim().parse().ok();
+        } else if let Some(auths) = value(trimmed, "Authentication suites") {
+            let auths = auths.split_whitespace().map(str::to_string).collect();
+            match block {
+                Block::Rsn => entry.rsn = Some(auths),
+                Block::Wpa => entry.wpa = Some(auths),
+                Block::None => {}
+            }
+        }
+    }
+
+    if let Some(entry) = entry {
+        res.push(access_point(interface, entry));
+    }
+
+    res
+}
+
+fn interfaces() -> Result<Vec<String>> {
+    let mut res = Vec::new();
+
+    for entry in std::fs::read_dir(SYS_CLASS_NET).map_err(Error::from)? {
+        let path = entry.map_err(Error::from)?.path();
+
+        if path.join("wireless").exists() || path.join("phy80211").exists() {
+            if let Some(name) = path.file_name() {
+                res.push(name.to_string_lossy().into_owned());
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+fn iw_error(stderr: &str) -> Error {
+    let message = stderr.trim().to_string();
+    let code = message
+        .rsplit_once('(')
+        .and_then(|(_, code)| code.trim_end_matches(')').parse::<i32>().ok());
+
+    match code {
+        Some(code) if code < 0 => Error::with_kind(ErrorKind::from_errno(-code), message),
+        // iw prints usage or "nl80211 not found" when it cannot run the command at all
+        _ => Error::with_kind(ErrorKind::Unsupported, message),
+    }
+}
+
+fn iw(interface: &str, args: &[&str]) -> Result<String> {
+    let output = Command::new("iw")
+        .arg("dev")
+        .arg(interface)
+        .args(args)
+        .output()
+        .map_err(|error| match error.kind() {
+            std::io::ErrorKind::NotFound => Error::unsupported("iw is not installed"),
+            _ => Error::from(error),
+        })?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(iw_error(&String::from_utf8_lossy(&output.stderr)))
+    }
+}
+
+fn scan_interface(interface: &str) -> Result<Vec<AccessPoint>> {
+    let output = match iw(interface, &["scan"]) {
+        Ok(output) => output,
+        Err(error) if error.kind() == &ErrorKind::Unsupported => return Err(error),
+        Err(_) => iw(interface, &["scan", "dump"])?,
+    };
+
+    Ok(parse_scan_dump(interface, &output))
+}
+
+pub fn scan() -> Result<Vec<AccessPoint>> {
+    let mut res = Vec::new();
+    let mut error = None;
+    let mut scanned = false;
+
+    for interface in interfaces()? {
+        match scan_interface(&interface) {
+            Ok(access_points) => {
+                scanned = true;
+                res.extend(access_points);
+            }
+            Err(e) => error = Some(e),
+        }
+    }
+
+    match error {
+        Some(error) if !scanned => Err(error),
+        _ => Ok(res),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{iw_error, parse_scan_dump};
+    use crate::types::wifi::{Band, Security};
+    use crate::types::ErrorKind;
+
+    #[test]
+    fn scan_dump() {
+        let aps = parse_scan_dump(
+            "wlan0",
+            "BSS 00:11:22:33:44:55(on wlan0) -- associated\n\tTSF: 1234 usec\n\tfreq: 2437.0\n\tbeacon interval: 100 TUs\n\tcapability: ESS Privacy ShortSlotTime (0x0411)\n\tsignal: -53.00 dBm\n\tSSID: home\n\tDS Parameter set: channel 6\n\tRSN:\t * Version: 1\n\t\t * Group cipher: CCMP\n\t\t * Pairwise ciphers: CCMP\n\t\t * Authentication suites: PSK SAE\n\t\t * Capabilities: 16-PTKSA-RC 1-GTKSA-RC (0x000c)\nBSS 66:77:88:99:aa:bb(on wlan0)\n\tfreq: 5180\n\tcapability: ESS Privacy (0x0011)\n\tsignal: -71.00 dBm\n\tSSID: \n\tWPA:\t * Version: 1\n\t\t * Authentication suites: IEEE 802.1X\nBSS cc:dd:ee:ff:00:11(on wlan0)\n\tfreq: 2412\n\tcapability: ESS (0x0001)\n\tsignal: -80.00 dBm\n\tSSID: cafe\n",
+        );
+
+        assert_eq!(aps.len(), 3);
+        assert_eq!(aps[0].bssid, "00:11:22:33:44:55");
+        assert!(aps[0].associated);
+        assert_eq!(aps[0].ssid.as_deref(), Some("home"));
+        assert_eq!(aps[0].channel, Some(6));
+        assert_eq!(aps[0].band, Some(Band::Ghz2_4));
+        assert_eq!(aps[0].rssi, Some(-53));
+        assert_eq!(aps[0].security, Security::WPA3Personal);
+        assert_eq!(aps[1].ssid, None);
+        assert_eq!(aps[1].channel, Some(36));
+        assert_eq!(aps[1].band, Some(Band::Ghz5));
+        assert_eq!(aps[1].security, Security::WPAEnterprise);
+        assert_eq!(aps[2].security, Security::Open);
+    }
+
+    #[test]
+    fn iw_errors() {
+        assert_eq!(
+            iw_error("command failed: Operation not permitted (-1)\n").kind(),
+            &ErrorKind::PermissionDenied
+        );
+        assert_eq!(
+            iw_error("command failed: No such device (-19)\n").kind(),
+            &ErrorKind::NotFound
+        );
+        assert_eq!(
+            iw_error("command failed: Device or resource busy (-16)\n").kind(),
+            &ErrorKind::Os(libc::EBUSY)
+        );
+        assert_eq!(
+            iw_error("Usage: iw [options]").kind(),
+            &ErrorKind::Unsupported
+        );
+        assert_eq!(
+            iw_error("nl80211 not found.\n").kind(),
+            &ErrorKind::Unsupported
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use crate::types::sockets::{Protocol, Socket, TcpState};
+use crate::types::{Error, Result};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::PathBuf;
+
+use super::procfs::read_string;
+
+fn parse_ip(hex: &str) -> Option<IpAddr> {
+    match hex.len() {
+        8 => {
+            let word = u32::from_str_radix(hex, 16).ok()?;
+            Some(IpAddr::V4(Ipv4Addr::from(word.to_ne_bytes())))
+        }
+        32 => {
+            let mut bytes = [0u8; 16];
+            for i in 0..4 {
+                let word = u32::from_str_radix(&hex[i * 8..(i + 1) * 8], 16).ok()?;
+                bytes[i * 4..(i + 1) * 4].copy_from_slice(&word.to_ne_bytes());
+            }
+            Some(IpAddr::V6(Ipv6Addr::from(bytes)))
+        }
+        _ => None,
+    }
+}
+
+fn parse_addr(field: &str) -> Option<SocketAddr> {
+    let mut parts = field.splitn(2, ':');
+    let ip = parse_ip(parts.next()?)?;
+    let port = u16::from_str_radix(parts.next()?, 16).ok()?;
+
+    Some(SocketAddr::new(ip, port))
+}
+
+fn parse_queues(field: &str) -> (u64, u64) {
+    let mut parts = field.splitn(2, ':');
+    let tx = parts
+        .next()
+        .and_then(|v| u64::from_str_radix(v, 16).ok())
+        .unwrap_or(0);
+    let rx = parts
+        .next()
+        .and_then(|v| u64::from_str_radix(v, 16).ok())
+        .unwrap_or(0);
+
+    (tx, rx)
+}
+
+pub fn parse_inet(content: &str, protocol: Protocol) -> Vec<Socket> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            if fields.len() < 10 {
+                return None;
+            }
+
+            let (tx_queue, rx_queue) = parse_queues(fields[4]);
+            let state = match protocol {
+                Protocol::Tcp | Protocol::Tcp6 => {
+                    u8::from_str_radix(fields[3], 16).ok().map(TcpState::from)
+                }
+                _ => None,
+            };
+
+            Some(Socket {
+                protocol,
+                local: parse_addr(fields[1]),
+                remote: parse_addr(fields[2]),
+                path: None,
+                state,
+                inode: fields[9].parse().unwrap_or(0),
+                tx_queue,
+                rx_queue,
+                uid: fields[7].parse().ok(),
+                pid: None,
+            })
+        })
+        .collect()
+}
+
+fn skip_fields(line: &str, n: usize) -> &str {
+    let mut rest = line.trim_start();
+    for _ in 0..n {
+        rest = match rest.find(char::is_whitespace) {
+            Some(i) => rest[i..].trim_start(),
+            None => "",
+        };
+    }
+    rest
+}
+
+pub fn parse_unix(content: &str) -> Vec<Socket> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            if fields.len() < 7 {
+                return None;
+            }
+
+            let path = skip_fields(line, 7);
+
+            Some(Socket {
+                protocol: Protocol::Unix,
+                local: None,
+                remote: None,
+                path: if path.is_empty() {
+                    None
+                } else {
+                    Some(PathBuf::from(path))
+                },
+                state: None,
+                inode: fields[6].parse().unwrap_or(0),
+                tx_queue: 0,
+                rx_queue: 0,
+                uid: None,
+                pid: None,
+            })
+        })
+        .collect()
+}
+
+fn socket_owners() -> HashMap<u64, i32> {
+    let mut res = HashMap::new();
+    let procs = match std::fs::read_dir("/proc") {
+        Ok(procs) => procs,
+        Err(_) => return res,
+    };
+
+    for proc in procs.filter_map(|entry| entry.ok()) {
+        let pid: i32 = match proc.file_name().to_str().and_then(|n| n.parse().ok()) {
+            Some(pid) => pid,
+            None => continue,
+        };
+        let fds = match std::fs::read_dir(proc.path().join("fd")) {
+            Ok(fds) => fds,
+            Err(_) => continue,
+        };
+
+        for fd in fds.filter_map(|entry| entry.ok()) {
+            if let Ok(target) = std::fs::read_link(fd.path()) {
+                let target = target.to_string_lossy();
+                if let Some(inode) = target
+                    .strip_prefix("socket:[")
+                    .and_then(|t| t.strip_suffix(']'))
+                    .and_then(|t| t.parse().ok())
+                {
+                    res.entry(inode).or_insert(pid);
+                }
+            }
+        }
+    }
+
+    res
+}
+
+pub fn count_states(content: &str, res: &mut HashMap<TcpState, usize>) {
+    for line in content.lines().skip(1) {
+        if let Some(state) = line
+            .split_whitespace()
+            .nth(3)
+            .and_then(|st| u8::from_str_radix(st, 16).ok())
+        {
+            *res.entry(TcpState::from(state)).or_insert(0) += 1;
+        }
+    }
+}
+
+pub fn tcp_summary() -> Result<HashMap<TcpState, usize>> {
+    let content = read_string("/proc/net/tcp")
+        .ok_or_else(|| Error::not_found("Cannot read /proc/net/tcp"))?;
+
+    let mut res = HashMap::new();
+    count_states(&content, &mut res);
+    if let Some(content) = read_string("/proc/net/tcp6") {
+        count_states(&content, &mut res);
+    }
+
+    Ok(res)
+}
+
+pub fn all() -> Result<Vec<Socket>> {
+    let tables = [
+        ("/proc/net/tcp", Protocol::Tcp),
+        ("/proc/net/tcp6", Protocol::Tcp6),
+        ("/proc/net/udp", Protocol::Udp),
+        ("/proc/net/udp6", Protocol::Udp6),
+    ];
+
+    let mut res = Vec::new();
+    for (path, protocol) in tables.iter() {
+        match read_string(path) {
+            Some(content) => res.append(&mut parse_inet(&content, *protocol)),
+            None if *protocol == Protocol::Tcp => {
+                return Err(Error::not_found(format!("Cannot read {}", path)))
+            }
+            None => {}
+        }
+    }
+    if let Some(content) = read_string("/proc/net/unix") {
+        res.append(&mut parse_unix(&content));
+    }
+
+    let owners = socket_owners();
+    for socket in res.iter_mut() {
+        socket.pid = owners.get(&socket.inode).copied();
+    }
+
+    Ok(res)
+}
+
+#[cfg(all(test, target_endian = "little"))]
+mod test {
+    use super::{count_states, parse_inet, parse_unix};
+    use crate::types::sockets::{Protocol, TcpState};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    #[test]
+    fn inet_tables() {
+        let tcp = parse_inet(
+            "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n   \
+             0: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 23817 1 0000000000000000 100 0 0 10 0\n   \
+             1: 0F02000A:0016 0202000A:C8E4 01 00000024:00000000 01:00000018 00000000     0        0 41256 4 0000000000000000 20 4 29 10 -1\n",
+            Protocol::Tcp,
+        );
+        assert_eq!(tcp.len(), 2);
+        assert_eq!(tcp[0].local, Some("127.0.0.1:631".parse().unwrap()));
+        assert_eq!(tcp[0].state, Some(TcpState::Listen));
+        assert_eq!((tcp[0].uid, tcp[0].inode), (Some(0), 23817));
+        assert_eq!(tcp[1].local, Some("10.0.2.15:22".parse().unwrap()));
+        assert_eq!(tcp[1].remote, Some("10.0.2.2:51428".parse().unwrap()));
+        assert_eq!(tcp[1].state, Some(TcpState::Established));
+        assert_eq!((tcp[1].tx_queue, tcp[1].rx_queue), (36, 0));
+
+        let tcp6 = parse_inet(
+            "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n   \
+             0: 00000000000000000000000001000000:0CEA 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 30811 1 0000000000000000 100 0 0 10 0\n   \
+             1: 0000000000000000FFFF00000100007F:1F90 0000000000000000FFFF00000100007F:D431 01 00000000:00000000 00:00000000 00000000  1000        0 30900 1 0000000000000000 20 4 30 10 -1\n",
+            Protocol::Tcp6,
+        );
+        assert_eq!(tcp6[0].local, Some("[::1]:3306".parse().unwrap()));
+        assert_eq!(tcp6[0].uid, Some(1000));
+        assert_eq!(
+            tcp6[1].local,
+            Some("[::ffff:127.0.0.1]:8080".parse().unwrap())
+        );
+        assert_eq!(
+            tcp6[1].remote,
+            Some("[::ffff:127.0.0.1]:54321".parse().unwrap())
+        );
+
+        let udp = parse_inet(
+            "   sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops\n  \
+             0: 3500007F:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000   101        0 19000 2 0000000000000000 0\n",
+            Protocol::Udp,
+        );
+        assert_eq!(udp[0].local, Some("127.0.0.53:53".parse().unwrap()));
+        assert_eq!(udp[0].state, None);
+    }
+
+    #[test]
+    fn tcp_states() {
+        let mut states = HashMap::new();
+        count_states(
+            "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n   \
+             0: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 23817 1 0000000000000000 100 0 0 10 0\n   \
+             1: 0F02000A:0016 0202000A:C8E4 01 00000024:00000000 01:00000018 00000000     0        0 41256 4 0000000000000000 20 4 29 10 -1\n   \
+             2: 0F02000A:0016 0202000A:C8E5 01 00000000:00000000 00:00000000 00000000     0        0 41257 4 0000000000000000 20 4 29 10 -1\n",
+            &mut states,
+        );
+
+        assert_eq!(states.len(), 2);
+        assert_eq!(states[&TcpState::Listen], 1);
+        assert_eq!(states[&TcpState::Established], 2);
+    }
+
+    #[test]
+    fn unix_table() {
+        let unix = parse_unix(
+            "Num       RefCount Protocol Flags    Type St Inode Path\n\
+             0000000000000000: 00000002 00000000 00010000 0001 01 20961 /run/user/1000/my socket\n\
+             0000000000000000: 00000003 00000000 00000000 0001 03 20962\n\
+             0000000000000000: 00000002 00000000 00010000 0001 01 20963 @/tmp/.X11-unix/X0\n",
+        );
+
+        assert_eq!(unix.len(), 3);
+        assert_eq!(
+            unix[0].path,
+            Some(PathBuf::from("/run/user/1000/my socket"))
+        );
+        assert_eq!(unix[0].inode, 20961);
+        assert_eq!(unix[1].path, None);
+        assert_eq!(unix[2].path, Some(PathBuf::from("@/tmp/.X11-unix/X0")));
+    }
+}
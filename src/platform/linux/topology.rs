@@ -0,0 +1,167 @@
+#![allow(non_camel_case_types)]
+
+use crate::types::network::{Link, Vlan};
+use crate::types::{Error, Result};
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::path::Path;
+
+use super::procfs::{read_string, read_value};
+
+const SYS_CLASS_NET: &str = "/sys/class/net";
+const SIOCETHTOOL: libc::c_ulong = 0x8946;
+const ETHTOOL_GDRVINFO: u32 = 0x3;
+
+#[repr(C)]
+struct ethtool_drvinfo {
+    cmd: u32,
+    driver: [c_char; 32],
+    version: [c_char; 32],
+    fw_version: [c_char; 32],
+    bus_info: [c_char; 32],
+    erom_version: [c_char; 32],
+    reserved2: [c_char; 12],
+    n_priv_flags: u32,
+    n_stats: u32,
+    testinfo_len: u32,
+    eedump_len: u32,
+    regdump_len: u32,
+}
+
+#[repr(C)]
+struct ifreq {
+    ifr_name: [c_char; libc::IFNAMSIZ],
+    data: *mut c_void,
+    _pad: [u8; 16],
+}
+
+pub fn parse_vlan_config(content: &str) -> HashMap<String, Vlan> {
+    content
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let mut fields = line.split('|').map(str::trim);
+            let name = fields.next()?;
+            let id = fields.next()?.parse().ok()?;
+            let parent = fields.next()?;
+
+            Some((
+                name.to_string(),
+                Vlan {
+                    parent: parent.to_string(),
+                    id,
+                },
+            ))
+        })
+        .collect()
+}
+
+fn adjacent(iface: &Path, prefix: &str) -> Vec<String> {
+    let mut res = std::fs::read_dir(iface)
+        .map(|dir| {
+            dir.filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_string_lossy().into_owned();
+                    name.strip_prefix(prefix).map(str::to_string)
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    res.sort();
+    res
+}
+
+fn master(iface: &Path) -> Option<String> {
+    std::fs::read_link(iface.join("master"))
+        .ok()?
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+}
+
+fn driver(name: &str) -> Option<String> {
+    if name.len() >= libc::IFNAMSIZ {
+        return None;
+    }
+
+    let mut info: ethtool_drvinfo = unsafe { mem::zeroed() };
+    info.cmd = ETHTOOL_GDRVINFO;
+    let mut req: ifreq = unsafe { mem::zeroed() };
+    for (dst, src) in req.ifr_name.iter_mut().zip(name.bytes()) {
+        *dst = src as c_char;
+    }
+    req.data = &mut info as *mut _ as *mut c_void;
+
+    unsafe {
+        let sock = libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0);
+        if sock < 0 {
+            return None;
+        }
+        let res = libc::ioctl(sock, SIOCETHTOOL as _, &mut req);
+        libc::close(sock);
+
+        if res < 0 {
+            None
+        } else {
+            Some(
+                CStr::from_ptr(info.driver.as_ptr())
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+}
+
+pub fn topology() -> Result<Vec<Link>> {
+    let mut vlans = read_string("/proc/net/vlan/config")
+        .map(|config| parse_vlan_config(&config))
+        .unwrap_or_default();
+    let mut res = Vec::new();
+
+    for entry in std::fs::read_dir(SYS_CLASS_NET).map_err(Error::from)? {
+        let entry = entry.map_err(Error::from)?;
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let index: u32 = read_value(path.join("ifindex")).unwrap_or(0);
+        let iflink: Option<u32> = read_value(path.join("iflink"));
+        let lower = adjacent(&path, "lower_");
+
+        // Tunnels bound to a device also report its index as iflink, so only
+        // trust it as a peer for veth pairs.
+        let peer_index = iflink
+            .filter(|iflink| *iflink != 0 && *iflink != index && lower.is_empty())
+            .filter(|_| driver(&name).as_deref() == Some("veth"));
+
+        res.push(Link {
+            index,
+            master: master(&path),
+            upper: adjacent(&path, "upper_"),
+            vlan: vlans.remove(&name),
+            peer_index,
+            lower,
+            name,
+        });
+    }
+
+    res.sort_by_key(|link| link.index);
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_vlan_config;
+
+    #[test]
+    fn vlan_config() {
+        let vlans = parse_vlan_config(
+            "VLAN Dev name	 | VLAN ID\nName-Type: VLAN_NAME_TYPE_RAW_PLUS_VID_NO_PAD\neth0.100       | 100  | eth0\nvlan42         | 42  | bond0\n",
+        );
+
+        assert_eq!(vlans.len(), 2);
+        assert_eq!(vlans["eth0.100"].parent, "eth0");
+        assert_eq!(vlans["eth0.100"].id, 100);
+        assert_eq!(vlans["vlan42"].parent, "bond0");
+    }
+}
@@ -0,0 +1,85 @@
+use crate::platform::unix::users::{
+    field_i16, field_i32, field_string, login_time, with_idle, USER_PROCESS,
+};
+use crate::types::{users::Session, Error, Result};
+
+const UTMP_FILE: &str = "/var/run/utmp";
+const UTMP_SIZE: usize = 384;
+
+const UT_TYPE: usize = 0;
+const UT_PID: usize = 4;
+const UT_LINE: usize = 8;
+const UT_LINESIZE: usize = 32;
+const UT_USER: usize = 44;
+const UT_NAMESIZE: usize = 32;
+const UT_HOST: usize = 76;
+const UT_HOSTSIZE: usize = 256;
+const UT_TV_SEC: usize = 340;
+const UT_TV_USEC: usize = 344;
+
+pub fn parse_utmp(data: &[u8]) -> Vec<Session> {
+    data.chunks_exact(UTMP_SIZE)
+        .filter(|record| field_i16(record, UT_TYPE) == USER_PROCESS)
+        .map(|record| {
+            let host = field_string(record, UT_HOST, UT_HOSTSIZE);
+
+            Session {
+                user: field_string(record, UT_USER, UT_NAMESIZE),
+                tty: field_string(record, UT_LINE, UT_LINESIZE),
+                host: if host.is_empty() { None } else { Some(host) },
+                login_time: login_time(
+                    i64::from(field_i32(record, UT_TV_SEC)),
+                    i64::from(field_i32(record, UT_TV_USEC)),
+                ),
+                pid: field_i32(record, UT_PID),
+                idle: None,
+            }
+        })
+        .collect()
+}
+
+pub fn sessions() -> Result<Vec<Session>> {
+    let data = std::fs::read(UTMP_FILE).map_err(Error::from)?;
+
+    Ok(with_idle(parse_utmp(&data)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn record(ut_type: i16, pid: i32, line: &str, user: &str, host: &str, sec: i32) -> Vec<u8> {
+        let mut buf = vec![0u8; UTMP_SIZE];
+        buf[UT_TYPE..UT_TYPE + 2].copy_from_slice(&ut_type.to_ne_bytes());
+        buf[UT_PID..UT_PID + 4].copy_from_slice(&pid.to_ne_bytes());
+        buf[UT_LINE..UT_LINE + line.len()].copy_from_slice(line.as_bytes());
+        buf[UT_USER..UT_USER + user.len()].copy_from_slice(user.as_bytes());
+        buf[UT_HOST..UT_HOST + host.len()].copy_from_slice(host.as_bytes());
+        buf[UT_TV_SEC..UT_TV_SEC + 4].copy_from_slice(&sec.to_ne_bytes());
+        buf
+    }
+
+    #[test]
+    fn user_processes_only() {
+        let mut data = record(2, 0, "~", "reboot", "6.1.0", 1_700_000_000);
+        data.extend(record(7, 1234, "pts/0", "alice", "10.0.0.7", 1_700_000_100));
+        data.extend(record(8, 1200, "pts/1", "", "", 1_700_000_050));
+        data.extend(record(7, 987, "tty1", "root", "", 1_700_000_200));
+        data.extend(&[0u8; 10]);
+
+        let sessions = parse_utmp(&data);
+
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].user, "alice");
+        assert_eq!(sessions[0].tty, "pts/0");
+        assert_eq!(sessions[0].host.as_deref(), Some("10.0.0.7"));
+        assert_eq!(sessions[0].pid, 1234);
+        assert_eq!(
+            sessions[0].login_time,
+            UNIX_EPOCH + Duration::from_secs(1_700_000_100)
+        );
+        assert_eq!(sessions[1].user, "root");
+        assert_eq!(sessions[1].host, None);
+    }
+}
@@ -0,0 +1,186 @@
+#![allow(non_camel_case_types)]
+
+use crate::types::network::Wireless;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::mem;
+use std::os::raw::c_char;
+
+use super::procfs::read_string;
+
+const SIOCGIWFREQ: libc::c_ulong = 0x8B05;
+const SIOCGIWRATE: libc::c_ulong = 0x8B21;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct iw_freq {
+    m: i32,
+    e: i16,
+    i: u8,
+    flags: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct iw_param {
+    value: i32,
+    fixed: u8,
+    disabled: u8,
+    flags: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union iwreq_data {
+    freq: iw_freq,
+    bitrate: iw_param,
+    _pad: [u8; 16],
+}
+
+#[repr(C)]
+struct iwreq {
+    ifr_name: [c_char; libc::IFNAMSIZ],
+    u: iwreq_data,
+}
+
+fn level(value: &str) -> Option<i32> {
+    let value = value.trim_end_matches('.').parse::<i32>().ok()?;
+
+    if value == -256 || value == 0 {
+        None
+    } else if value > 0 {
+        Some(value - 256)
+    } else {
+        Some(value)
+    }
+}
+
+pub fn parse_wireless(content: &str) -> HashMap<String, Wireless> {
+    content
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let (name, rest) = line.split_once(':')?;
+            let fields = rest.split_whitespace().collect::<Vec<_>>();
+            if fields.len() < 4 {
+                return None;
+            }
+
+            Some((
+                name.trim().to_string(),
+                Wireless {
+                    quality: fields[1].trim_end_matches('.').parse().ok(),
+                    signal: level(fields[2]),
+                    noise: level(fields[3]),
+                    ..Default::default()
+                },
+            ))
+        })
+        .collect()
+}
+
+pub fn channel(frequency: u32) -> Option<u32> {
+    match frequency {
+        2484 => Some(14),
+        2412..=2472 => Some((frequency - 2407) / 5),
+        5955..=7115 => Some((frequency - 5950) / 5),
+        5000..=5900 => Some((frequency - 5000) / 5),
+        _ => None,
+    }
+}
+
+fn wext(name: &str, request: libc::c_ulong) -> Option<iwreq_data> {
+    if name.len() >= libc::IFNAMSIZ {
+        return None;
+    }
+
+    let mut req: iwreq = unsafe { mem::zeroed() };
+    for (dst, src) in req.ifr_name.iter_mut().zip(name.bytes()) {
+        *dst = src as c_char;
+    }
+
+    unsafe {
+        let sock = libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0);
+        if sock < 0 {
+            return None;
+        }
+        let res = libc::ioctl(sock, request as _, &mut req);
+        libc::close(sock);
+
+        if res < 0 {
+            None
+        } else {
+            Some(req.u)
+        }
+    }
+}
+
+fn frequency(wireless: &mut Wireless, name: &str) {
+    let freq = match wext(name, SIOCGIWFREQ) {
+        Some(data) => unsafe { data.freq },
+        None => return,
+    };
+
+    if freq.e == 0 && freq.m < 1000 {
+        wireless.channel = u32::try_from(freq.m).ok();
+    } else {
+        let hz = f64::from(freq.m) * 10f64.powi(i32::from(freq.e));
+        wireless.frequency = Some((hz / 1_000_000.0).round() as u32);
+        wireless.channel = wireless.frequency.and_then(channel);
+    }
+}
+
+fn tx_rate(name: &str) -> Option<f64> {
+    let bitrate = unsafe { wext(name, SIOCGIWRATE)?.bitrate };
+
+    if bitrate.disabled != 0 || bitrate.value <= 0 {
+        None
+    } else {
+        Some(f64::from(bitrate.value) / 1_000_000.0)
+    }
+}
+
+fn fill(wireless: &mut Wireless, name: &str) {
+    frequency(wireless, name);
+    wireless.tx_rate = tx_rate(name);
+}
+
+pub fn all() -> HashMap<String, Wireless> {
+    let mut res = read_string("/proc/net/wireless")
+        .as_deref()
+        .map(parse_wireless)
+        .unwrap_or_default();
+
+    for (name, wireless) in res.iter_mut() {
+        fill(wireless, name);
+    }
+
+    res
+}
+
+pub fn get(name: &str) -> Wireless {
+    let mut res = Wireless::default();
+    fill(&mut res, name);
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::{channel, parse_wireless};
+
+    #[test]
+    fn proc_net_wireless() {
+        let wireless = parse_wireless(
+            "Inter-| sta-|   Quality        |   Discarded packets               | Missed | WE\n face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22\nwlp2s0: 0000   54.  -56.  -256        0      0      0      0     12        0\n  wlan1: 0000   40.  190.  161.       0      0      0      0      0        0\n",
+        );
+
+        assert_eq!(wireless.len(), 2);
+        assert_eq!(wireless["wlp2s0"].quality, Some(54.0));
+        assert_eq!(wireless["wlp2s0"].signal, Some(-56));
+        assert_eq!(wireless["wlp2s0"].noise, None);
+        assert_eq!(wireless["wlan1"].signal, Some(-66));
+        assert_eq!(wireless["wlan1"].noise, Some(-95));
+        assert_eq!(channel(2437), Some(6));
+        assert_eq!(channel(5180), Some(36));
+    }
+}
@@ -26,7 +26,7 @@ pub fn load() -> Result<CoresLoadInfo, Error> {
     };
 
     if err != 0 {
-        Err(Error::new(MachError::new(err)))
+        Err(MachError::new(err).into())
     } else {
         let mut cpus: CoresLoadInfo = Vec::with_capacity(processor_count as usize);
 
@@ -0,0 +1,30 @@
+use crate::types::misc::{Environment, Hypervisor};
+use libc::{c_int, c_void, sysctlbyname};
+
+fn vmm_present() -> bool {
+    let mut present: c_int = 0;
+    let mut len = std::mem::size_of::<c_int>();
+
+    let err = unsafe {
+        sysctlbyname(
+            b"kern.hv_vmm_present\0".as_ptr() as *const _,
+            &mut present as *mut _ as *mut c_void,
+            &mut len,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+
+    err == 0 && present != 0
+}
+
+pub fn get() -> Environment {
+    Environment {
+        hypervisor: if vmm_present() {
+            Some(Hypervisor::Unknown)
+        } else {
+            None
+        },
+        container: None,
+    }
+}
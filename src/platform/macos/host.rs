@@ -0,0 +1,72 @@
+use crate::platform::unix::host::{domainname, get_uname, timezone};
+use crate::types::{misc::Host, Result};
+use libc::{c_char, c_int, c_void, sysctlbyname, timespec};
+use std::ffi::CStr;
+
+use uuid::Uuid;
+
+extern "C" {
+    fn gethostuuid(id: *mut u8, wait: *const timespec) -> c_int;
+}
+
+fn sysctl_string(name: &str) -> Option<String> {
+    let name = std::ffi::CString::new(name).ok()?;
+    let mut len: usize = 0;
+
+    if unsafe {
+        sysctlbyname(
+            name.as_ptr(),
+            std::ptr::null_mut(),
+            &mut len,
+            std::ptr::null_mut(),
+            0,
+        )
+    } != 0
+    {
+        return None;
+    }
+
+    let mut buf: Vec<u8> = vec![0; len + 1];
+    if unsafe {
+        sysctlbyname(
+            name.as_ptr(),
+            buf.as_mut_ptr() as *mut c_void,
+            &mut len,
+            std::ptr::null_mut(),
+            0,
+        )
+    } != 0
+    {
+        return None;
+    }
+
+    let value = unsafe { CStr::from_ptr(buf.as_ptr() as *const c_char) };
+    Some(value.to_string_lossy().trim().to_string())
+}
+
+fn machine_id() -> Option<String> {
+    let mut id = [0u8; 16];
+    let wait: timespec = unsafe { std::mem::zeroed() };
+
+    if unsafe { gethostuuid(id.as_mut_ptr(), &wait) } != 0 {
+        None
+    } else {
+        Uuid::from_bytes(&id).ok().map(|uuid| format!("{}", uuid))
+    }
+}
+
+pub fn get() -> Result<Host> {
+    let uname = get_uname()?;
+
+    Ok(Host {
+        hostname: uname.nodename,
+        domainname: domainname(),
+        os_name: "macOS".to_string(),
+        os_version: sysctl_string("kern.osproductversion"),
+        os_id: Some("macos".to_string()),
+        kernel: uname.release,
+        arch: uname.machine,
+        machine_id: machine_id(),
+        timezone: timezone(),
+    })
+}
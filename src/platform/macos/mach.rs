@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types)]
 
+use crate::types::{Error, ErrorKind};
 use libc::strlen;
 use std::os::raw::{c_int, c_uint, c_ulong};
 use std::{fmt, slice, str};
@@ -141,6 +142,12 @@ impl std::error::Error for MachError {
     }
 }
 
+impl From<MachError> for Error {
+    fn from(error: MachError) -> Error {
+        Error::with_kind(ErrorKind::Mach(error.code()), error)
+    }
+}
+
 impl fmt::Display for MachError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", get_error_string(self.code()))
@@ -46,7 +46,7 @@ pub fn ram() -> Result<RAM> {
     };
 
     if err != 0 {
-        Err(Error::new(MachError::new(err)))
+        Err(MachError::new(err).into())
     } else {
         let mem_used = vmstat.active_count + vmstat.wire_count;
         let mem_total = mem_used + vmstat.inactive_count + vmstat.free_count;
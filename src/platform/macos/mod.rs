@@ -7,17 +7,31 @@ extern crate uuid;
 pub mod boottime;
 pub mod cpu;
 pub mod disk_arbitration;
+pub mod environment;
 pub mod fs;
+pub mod host;
 pub mod mach;
 pub mod mem;
 pub mod netif;
 pub mod power;
 pub mod thermal;
+pub mod users;
 pub mod wifi;
 
 pub mod imp {
     pub mod network {
         pub use super::super::netif::all;
+        pub use crate::platform::unix::resolv::dns;
+        use crate::types::{
+            network::{Link, Route},
+            Error, Result,
+        };
+        pub fn topology() -> Result<Vec<Link>> {
+            Err(Error::unsupported("Network topology unsupported"))
+        }
+        pub fn routes() -> Result<Vec<Route>> {
+            Err(Error::unsupported("Routing table unsupported"))
+        }
     }
 
     pub mod cpu {
@@ -34,14 +48,110 @@ pub mod imp {
     }
 
     pub mod power {
-        pub use super::super::power::sources;
+        use crate::types::{power::PowerSources, Result};
+
+        pub fn sources() -> Result<PowerSources> {
+            Ok(super::super::power::sources())
+        }
     }
 
     pub mod thermal {
-        pub use super::super::thermal::{cpus, custom, fans};
+        use crate::types::{thermal::Fans, Error, Result};
+
+        pub fn cpus() -> Result<Vec<f64>> {
+            super::super::thermal::cpus()
+                .ok_or_else(|| Error::not_found("CPU temperature sensors not available"))
+        }
+
+        pub fn custom(key: &str) -> Result<f64> {
+            super::super::thermal::custom(key)
+                .ok_or_else(|| Error::not_found(format!("Sensor {} not available", key)))
+        }
+
+        pub fn fans() -> Result<Fans> {
+            super::super::thermal::fans()
+                .ok_or_else(|| Error::not_found("Fan sensors not available"))
+        }
     }
 
     pub mod boottime {
-        pub use super::super::boottime::get;
+        use crate::types::{Error, Result};
+        use std::time::SystemTime;
+
+        pub fn get() -> Result<SystemTime> {
+            super::super::boottime::get().ok_or_else(|| Error::not_found("Boot time not available"))
+        }
+    }
+
+    pub mod environment {
+        pub use super::super::environment::get;
+    }
+
+    pub mod host {
+        pub use super::super::host::get;
+    }
+
+    pub mod users {
+        pub use super::super::users::sessions;
+    }
+
+    pub mod wifi {
+        use super::super::netif::wifi_names;
+        use super::super::wifi::WiFi;
+        use crate::types::{wifi::AccessPoint, Result};
+
+        pub fn scan() -> Result<Vec<AccessPoint>> {
+            let mut res = Vec::new();
+            let mut error = None;
+            let mut scanned = false;
+
+            for name in wifi_names() {
+                match WiFi::new(&name).and_then(|wifi| wifi.scan(&name)) {
+                    Ok(access_points) => {
+                        scanned = true;
+                        res.extend(access_points);
+                    }
+                    Err(e) => error = Some(e),
+                }
+            }
+
+            match error {
+                Some(error) if !scanned => Err(error.into()),
+                _ => Ok(res),
+            }
+        }
+    }
+
+    pub mod kernel {
+        use crate::types::{kernel::KernelActivity, Error, Result};
+        pub fn activity() -> Result<KernelActivity> {
+            Err(Error::unsupported("Kernel activity counters unsupported"))
+        }
+    }
+
+    pub mod limits {
+        use crate::types::{limits::Limits, Error, Result};
+        pub fn get() -> Result<Limits> {
+            Err(Error::unsupported("System limits unsupported"))
+        }
+    }
+
+    pub mod sockets {
+        use crate::types::sockets::{Socket, TcpState};
+        use crate::types::{Error, Result};
+        use std::collections::HashMap;
+        pub fn all() -> Result<Vec<Socket>> {
+            Err(Error::unsupported("Socket table unsupported"))
+        }
+        pub fn tcp_summary() -> Result<HashMap<TcpState, usize>> {
+            Err(Error::unsupported("Socket table unsupported"))
+        }
+    }
+
+    pub mod protocols {
+        use crate::types::{protocols::ProtocolCounters, Error, Result};
+        pub fn counters() -> Result<ProtocolCounters> {
+            Err(Error::unsupported("Protocol counters unsupported"))
+        }
     }
 }
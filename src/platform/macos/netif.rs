@@ -2,7 +2,7 @@
 
 use crate::types::{Error, Result};
 
-use crate::types::network::{NetworkInterface, Type};
+use crate::types::network::{InterfaceStats, NetworkInterface, Type, Wireless};
 
 use std::fmt::{self, Debug, Formatter};
 
@@ -15,6 +15,7 @@ use std::os::raw::{c_char, c_int, c_long, c_uchar, c_uint, c_void};
 use std::ffi::CStr;
 
 use std::collections::HashMap;
+use std::convert::TryFrom;
 
 use ifstructs::ifreq;
 
@@ -24,6 +25,7 @@ use libc::{
     close, ioctl, socket, sysctl, CTL_NET, IFF_UP, IPPROTO_IP, PF_INET6, PF_LINK, SOCK_DGRAM,
 };
 
+use super::super::unix::ifaddrs;
 use super::wifi::WiFi;
 
 const IFNAMSIZ: usize = 16;
@@ -199,6 +201,16 @@ struct if_rxpoll_stats {
     ifi_poll_interval_time: u64,
 }
 
+fn is_up(name: &str) -> bool {
+    let sock = unsafe { socket(PF_INET6, SOCK_DGRAM, IPPROTO_IP) };
+    let ifr = ifreq::from_name(name).unwrap();
+    unsafe {
+        ioctl(sock, SIOCGIFFLAGS, &ifr);
+        close(sock);
+    }
+    (ifr.get_flags() as i32 & IFF_UP) != 0
+}
+
 #[derive(Clone)]
 struct NetIf {
     ptr: *const c_void,
@@ -244,15 +256,7 @@ impl NetIf {
     pub fn is_up(&self) -> bool {
         match self.bsd_name() {
             None => false,
-            Some(name) => {
-                let sock = unsafe { socket(PF_INET6, SOCK_DGRAM, IPPROTO_IP) };
-                let ifr = ifreq::from_name(&name).unwrap();
-                unsafe {
-                    ioctl(sock, SIOCGIFFLAGS, &ifr);
-                    close(sock);
-                }
-                (ifr.get_flags() as i32 & IFF_UP) != 0
-            }
+            Some(name) => is_up(&name),
         }
     }
 }
@@ -337,68 +341,174 @@ fn get_ifmibdata() -> Result<HashMap<String, ifmibdata>> {
     _get_ifmibdata(if_count()?)
 }
 
-pub fn all() -> Result<Vec<NetworkInterface>> {
-    let ifmib = get_ifmibdata()?;
+fn stats(ifm: &ifmibdata) -> InterfaceStats {
+    let data = ifm.ifmd_data;
+
+    InterfaceStats {
+        rx_packets: data.ifi_ipackets,
+        tx_packets: data.ifi_opackets,
+        rx_errors: data.ifi_ierrors,
+        tx_errors: data.ifi_oerrors,
+        rx_dropped: data.ifi_iqdrops,
+        tx_dropped: u64::from(ifm.ifmd_snd_drops),
+        collisions: data.ifi_collisions,
+        rx_multicast: data.ifi_imcasts,
+        tx_multicast: data.ifi_omcasts,
+    }
+}
+
+fn speed(ifm: &ifmibdata) -> Option<u64> {
+    let baudrate = ifm.ifmd_data.ifi_baudrate;
+
+    if baudrate == 0 {
+        None
+    } else {
+        Some(baudrate / 1_000_000)
+    }
+}
+
+fn sc_type(t: &str) -> Type {
+    match t {
+        "Ethernet" => Type::Wired,
+        "IEEE80211" => Type::WiFi,
+        "Bridge" => Type::Bridge,
+        "Bond" => Type::Bond,
+        "VLAN" => Type::Vlan,
+        "WWAN" => Type::Cellular,
+        "PPP" | "IPSec" | "L2TP" | "6to4" => Type::Tunnel,
+        other => Type::Other(other.to_string()),
+    }
+}
+
+fn bsd_type(name: &str) -> Type {
+    let prefix = name.trim_end_matches(|c: char| c.is_ascii_digit());
+
+    match prefix {
+        "lo" => Type::Loopback,
+        "en" => Type::Wired,
+        "bridge" => Type::Bridge,
+        "bond" => Type::Bond,
+        "vlan" => Type::Vlan,
+        "utun" | "gif" | "stf" | "ipsec" | "ppp" => Type::Tunnel,
+        "awdl" | "llw" | "anpi" | "ap" | "feth" | "vmenet" => Type::Virtual,
+        "pdp_ip" => Type::Cellular,
+        other => Type::Other(other.to_string()),
+    }
+}
+
+fn interface(
+    name: String,
+    display_name: Option<String>,
+    itype: Type,
+    is_up: bool,
+    ifm: &ifmibdata,
+    addrs: &HashMap<String, ifaddrs::IfAddrs>,
+) -> NetworkInterface {
+    let addr = addrs.get(&name).cloned().unwrap_or_default();
+
+    NetworkInterface {
+        display_name,
+        itype,
+        is_up,
+        bssid: None,
+        ssid: None,
+        wireless: None,
+        up: ifm.ifmd_data.ifi_obytes,
+        down: ifm.ifmd_data.ifi_ibytes,
+        stats: stats(ifm),
+        tx_bytes: ifm.ifmd_data.ifi_obytes,
+        rx_bytes: ifm.ifmd_data.ifi_ibytes,
+        counters: stats(ifm),
+        rates: None,
+        mac: addr.mac,
+        mtu: Some(ifm.ifmd_data.ifi_mtu),
+        addresses: addr.addresses,
+        speed: speed(ifm),
+        duplex: None,
+        carrier: None,
+        name,
+    }
+}
 
+pub fn wifi_names() -> Vec<String> {
     unsafe {
-        Ok(
-            CFArray::<*const c_void>::wrap_under_get_rule(SCNetworkInterfaceCopyAll())
-                .iter()
-                .map(|ptr| NetIf { ptr: *ptr })
-                .filter_map(|netif| {
-                    if netif.bsd_name().is_some() && netif.interface_type().is_some() {
-                        let t = netif.interface_type().unwrap();
-                        if t == "Ethernet" {
-                            let name = netif.bsd_name().unwrap();
-                            let up = ifmib.get(&name)?.ifmd_data.ifi_obytes;
-                            let down = ifmib.get(&name)?.ifmd_data.ifi_ibytes;
-
-                            Some(NetworkInterface {
-                                name,
-                                display_name: netif.display_name(),
-                                itype: Type::Wired,
-                                is_up: netif.is_up(),
-                                bssid: None,
-                                ssid: None,
-                                up: up,
-                                down: down,
-                            })
-                        } else if t == "IEEE80211" {
-                            let name = netif.bsd_name().unwrap();
-                            let wifi = WiFi::new(&name).ok()?;
-                            let ifm = ifmib.get(&name)?;
-                            let up = ifm.ifmd_data.ifi_obytes;
-                            let down = ifm.ifmd_data.ifi_ibytes;
-
-                            let (is_up, bssid, ssid) = if netif.is_up() {
-                                let is_up = wifi.on_power().ok()?;
-                                if is_up {
-                                    (true, wifi.bssid().ok(), wifi.ssid().ok())
-                                } else {
-                                    (false, None, None)
-                                }
-                            } else {
-                                (false, None, None)
-                            };
-
-                            Some(NetworkInterface {
-                                name: name,
-                                display_name: netif.display_name(),
-                                itype: Type::WiFi,
-                                is_up: is_up,
-                                bssid: bssid,
-                                ssid: ssid,
-                                up: up,
-                                down: down,
-                            })
+        CFArray::<*const c_void>::wrap_under_get_rule(SCNetworkInterfaceCopyAll())
+            .iter()
+            .map(|ptr| NetIf { ptr: *ptr })
+            .filter(|netif| netif.interface_type().as_deref() == Some("IEEE80211"))
+            .filter_map(|netif| netif.bsd_name())
+            .collect()
+    }
+}
+
+pub fn all() -> Result<Vec<NetworkInterface>> {
+    let ifmib = get_ifmibdata()?;
+    let addrs = ifaddrs::all()?;
+
+    let mut res = unsafe {
+        CFArray::<*const c_void>::wrap_under_get_rule(SCNetworkInterfaceCopyAll())
+            .iter()
+            .map(|ptr| NetIf { ptr: *ptr })
+            .filter_map(|netif| {
+                let name = netif.bsd_name()?;
+                let t = netif.interface_type()?;
+                let ifm = ifmib.get(&name)?;
+
+                if t == "IEEE80211" {
+                    let wifi = WiFi::new(&name).ok()?;
+
+                    let (is_up, bssid, ssid, wireless) = if netif.is_up() {
+                        let is_up = wifi.on_power().ok()?;
+                        if is_up {
+                            (
+                                true,
+                                wifi.bssid().ok(),
+                                wifi.ssid().ok(),
+                                Some(Wireless {
+                                    signal: wifi.rssi().ok(),
+                                    noise: wifi.noise().ok(),
+                                    channel: wifi
+                                        .channel()
+                                        .ok()
+                                        .and_then(|c| u32::try_from(c).ok()),
+                                    tx_rate: wifi.tx_rate().ok().map(f64::from),
+                                    ..Default::default()
+                                }),
+                            )
                         } else {
-                            None
+                            (false, None, None, None)
                         }
                     } else {
-                        None
-                    }
-                })
-                .collect::<Vec<_>>(),
-        )
-    }
+                        (false, None, None, None)
+                    };
+
+                    let mut res =
+                        interface(name, netif.display_name(), Type::WiFi, is_up, ifm, &addrs);
+                    res.bssid = bssid;
+                    res.ssid = ssid;
+                    res.wireless = wireless;
+                    Some(res)
+                } else {
+                    Some(interface(
+                        name,
+                        netif.display_name(),
+                        sc_type(&t),
+                        netif.is_up(),
+                        ifm,
+                        &addrs,
+                    ))
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut rest = ifmib
+        .iter()
+        .filter(|(name, _)| !res.iter().any(|netif| &&netif.name == name))
+        .map(|(name, ifm)| interface(name.clone(), None, bsd_type(name), is_up(name), ifm, &addrs))
+        .collect::<Vec<_>>();
+    rest.sort_by(|a, b| a.name.cmp(&b.name));
+    res.append(&mut rest);
+
+    Ok(res)
 }
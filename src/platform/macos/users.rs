@@ -0,0 +1,51 @@
+use crate::platform::unix::users::{
+    field_i16, field_i32, field_string, login_time, with_idle, USER_PROCESS,
+};
+use crate::types::{users::Session, Error, Result};
+
+const UTMPX_FILE: &str = "/var/run/utmpx";
+const UTMPX_SIZE: usize = 640;
+
+const UT_USER: usize = 0;
+const UT_NAMESIZE: usize = 256;
+const UT_LINE: usize = 260;
+const UT_LINESIZE: usize = 32;
+const UT_PID: usize = 292;
+const UT_TYPE: usize = 296;
+const UT_TV_SEC: usize = 304;
+const UT_TV_USEC: usize = 312;
+const UT_HOST: usize = 320;
+const UT_HOSTSIZE: usize = 256;
+
+fn field_i64(buf: &[u8], offset: usize) -> i64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&buf[offset..offset + 8]);
+    i64::from_ne_bytes(bytes)
+}
+
+pub fn parse_utmpx(data: &[u8]) -> Vec<Session> {
+    data.chunks_exact(UTMPX_SIZE)
+        .filter(|record| field_i16(record, UT_TYPE) == USER_PROCESS)
+        .map(|record| {
+            let host = field_string(record, UT_HOST, UT_HOSTSIZE);
+
+            Session {
+                user: field_string(record, UT_USER, UT_NAMESIZE),
+                tty: field_string(record, UT_LINE, UT_LINESIZE),
+                host: if host.is_empty() { None } else { Some(host) },
+                login_time: login_time(
+                    field_i64(record, UT_TV_SEC),
+                    i64::from(field_i32(record, UT_TV_USEC)),
+                ),
+                pid: field_i32(record, UT_PID),
+                idle: None,
+            }
+        })
+        .collect()
+}
+
+pub fn sessions() -> Result<Vec<Session>> {
+    let data = std::fs::read(UTMPX_FILE).map_err(Error::from)?;
+
+    Ok(with_idle(parse_utmpx(&data)))
+}
@@ -1,7 +1,13 @@
-use core_foundation::base::TCFType;
+use crate::types::wifi::{AccessPoint, Band, Security};
+use crate::types::ErrorKind;
+use core_foundation::array::{CFArray, CFArrayRef};
+use core_foundation::base::{CFType, CFTypeRef, TCFType};
 use core_foundation::data::{CFData, CFDataRef};
+use core_foundation::dictionary::{CFDictionary, CFDictionaryRef};
+use core_foundation::number::{CFNumber, CFNumberRef};
 use core_foundation::string::{CFString, CFStringRef};
 use libc::{c_int, c_void, strerror, strlen};
+use std::convert::TryFrom;
 use std::{fmt, slice, str};
 
 // Thanks to http://newosxbook.com/articles/11208ellpA.html and
@@ -11,18 +17,32 @@ type Apple80211Ref = *const c_void;
 
 #[link(name = "Apple80211", kind = "framework")]
 extern "C" {
-    fn Apple80211Open(ptr: *const Apple80211Ref) -> i32;
+    fn Apple80211Open(ptr: *mut Apple80211Ref) -> i32;
     fn Apple80211BindToInterface(handle: Apple80211Ref, interface: CFStringRef) -> i32;
-    fn Apple80211GetPower(handle: Apple80211Ref, power: *const u32) -> i32;
+    fn Apple80211GetPower(handle: Apple80211Ref, power: *mut u32) -> i32;
     fn Apple80211CopyValue(
         handle: Apple80211Ref,
         field: c_int,
         dictCanBeLeftNULL: *const c_void,
-        outValue: *const c_void,
+        outValue: *mut c_void,
+    ) -> i32;
+    fn Apple80211Scan(
+        handle: Apple80211Ref,
+        list: *mut CFArrayRef,
+        parameters: CFDictionaryRef,
     ) -> i32;
     fn Apple80211Close(x: Apple80211Ref) -> i32;
 }
 
+const APPLE80211_C_FLAG_2GHZ: i32 = 0x8;
+const APPLE80211_C_FLAG_5GHZ: i32 = 0x10;
+const APPLE80211_C_FLAG_6GHZ: i32 = 0x2000;
+const APPLE80211_CAP_PRIVACY: i32 = 0x10;
+
+const AUTH_8021X: i32 = 1;
+const AUTH_SAE: i32 = 8;
+const AUTH_SUITE_B_192: i32 = 12;
+
 fn get_error_string(errno: i32) -> &'static str {
     match errno {
         -3931 => "Error",
@@ -71,6 +91,19 @@ impl std::error::Error for Error {
     }
 }
 
+impl From<Error> for crate::types::Error {
+    fn from(error: Error) -> crate::types::Error {
+        let kind = match error.0 {
+            -3930 => ErrorKind::PermissionDenied,
+            -3928 => ErrorKind::NotReady,
+            -3903 => ErrorKind::Unsupported,
+            code if code > 0 => ErrorKind::from_errno(code),
+            code => ErrorKind::Os(code),
+        };
+        crate::types::Error::with_kind(kind, error)
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", get_error_string(self.0))
@@ -87,13 +120,13 @@ impl fmt::Debug for Error {
 }
 
 fn get_ssid(handle: Apple80211Ref) -> Result<String, Error> {
-    let ptr: CFDataRef = std::ptr::null();
+    let mut ptr: CFDataRef = std::ptr::null();
     let res = unsafe {
         Apple80211CopyValue(
             handle,
             1,
             std::ptr::null_mut(),
-            &ptr as *const CFDataRef as *const c_void,
+            &mut ptr as *mut CFDataRef as *mut c_void,
         )
     };
 
@@ -107,13 +140,13 @@ fn get_ssid(handle: Apple80211Ref) -> Result<String, Error> {
 }
 
 fn get_bssid(handle: Apple80211Ref) -> Result<String, Error> {
-    let ptr: CFStringRef = std::ptr::null();
+    let mut ptr: CFStringRef = std::ptr::null();
     let res = unsafe {
         Apple80211CopyValue(
             handle,
             9,
             std::ptr::null_mut(),
-            &ptr as *const CFStringRef as *const c_void,
+            &mut ptr as *mut CFStringRef as *mut c_void,
         )
     };
 
@@ -124,13 +157,151 @@ fn get_bssid(handle: Apple80211Ref) -> Result<String, Error> {
     }
 }
 
+fn get_level(handle: Apple80211Ref, field: c_int) -> Result<i32, Error> {
+    let mut ptr: CFTypeRef = std::ptr::null();
+    let res = unsafe {
+        Apple80211CopyValue(
+            handle,
+            field,
+            std::ptr::null_mut(),
+            &mut ptr as *mut CFTypeRef as *mut c_void,
+        )
+    };
+
+    if res != 0 {
+        return Err(Error(res));
+    }
+    if ptr.is_null() {
+        return Err(Error(-3902));
+    }
+
+    let value = unsafe { CFType::wrap_under_create_rule(ptr) };
+    let number = match value.downcast::<CFArray>() {
+        Some(values) => values
+            .iter()
+            .next()
+            .map(|ptr| unsafe { CFNumber::wrap_under_get_rule(*ptr as CFNumberRef) }),
+        None => value.downcast::<CFNumber>(),
+    };
+
+    number.and_then(|n| n.to_i32()).ok_or(Error(-3902))
+}
+
+fn find(dict: &CFDictionary<CFString, CFType>, key: &'static str) -> Option<CFType> {
+    dict.find(CFString::from_static_string(key))
+        .map(|value| (*value).clone())
+}
+
+fn find_i32(dict: &CFDictionary<CFString, CFType>, key: &'static str) -> Option<i32> {
+    find(dict, key)?.downcast::<CFNumber>()?.to_i32()
+}
+
+fn find_string(dict: &CFDictionary<CFString, CFType>, key: &'static str) -> Option<String> {
+    find(dict, key)?
+        .downcast::<CFString>()
+        .map(|s| s.to_string())
+}
+
+fn auth_selectors(
+    dict: &CFDictionary<CFString, CFType>,
+    ie: &'static str,
+    key: &'static str,
+) -> Option<Vec<i32>> {
+    let ie = find(dict, ie)?.downcast::<CFDictionary>()?;
+    let ie =
+        unsafe { CFDictionary::<CFString, CFType>::wrap_under_get_rule(ie.as_concrete_TypeRef()) };
+
+    Some(
+        find(&ie, key)
+            .and_then(|auths| auths.downcast::<CFArray>())
+            .map(|auths| {
+                auths
+                    .iter()
+                    .filter_map(|ptr| {
+                        unsafe { CFNumber::wrap_under_get_rule(*ptr as CFNumberRef) }.to_i32()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+    )
+}
+
+fn security(dict: &CFDictionary<CFString, CFType>) -> Security {
+    if let Some(auths) = auth_selectors(dict, "RSN_IE", "IE_KEY_RSN_AUTHSELS") {
+        let is_wpa3 = auths.contains(&AUTH_SAE) || auths.contains(&AUTH_SUITE_B_192);
+        let is_enterprise = auths.contains(&AUTH_8021X) || auths.contains(&AUTH_SUITE_B_192);
+
+        match (is_wpa3, is_enterprise) {
+            (true, true) => Security::WPA3Enterprise,
+            (true, false) => Security::WPA3Personal,
+            (false, true) => Security::WPA2Enterprise,
+            (false, false) => Security::WPA2Personal,
+        }
+    } else if let Some(auths) = auth_selectors(dict, "WPA_IE", "IE_KEY_WPA_AUTHSELS") {
+        if auths.contains(&AUTH_8021X) {
+            Security::WPAEnterprise
+        } else {
+            Security::WPAPersonal
+        }
+    } else if find_i32(dict, "CAPABILITIES").unwrap_or(0) & APPLE80211_CAP_PRIVACY != 0 {
+        Security::WEP
+    } else {
+        Security::Open
+    }
+}
+
+fn band(flags: i32) -> Option<Band> {
+    if flags & APPLE80211_C_FLAG_6GHZ != 0 {
+        Some(Band::Ghz6)
+    } else if flags & APPLE80211_C_FLAG_5GHZ != 0 {
+        Some(Band::Ghz5)
+    } else if flags & APPLE80211_C_FLAG_2GHZ != 0 {
+        Some(Band::Ghz2_4)
+    } else {
+        None
+    }
+}
+
+fn frequency(channel: u32, band: Band) -> u32 {
+    match band {
+        Band::Ghz2_4 if channel == 14 => 2484,
+        Band::Ghz2_4 => 2407 + channel * 5,
+        Band::Ghz5 => 5000 + channel * 5,
+        Band::Ghz6 => 5950 + channel * 5,
+    }
+}
+
+fn access_point(
+    interface: &str,
+    associated: Option<&str>,
+    dict: &CFDictionary<CFString, CFType>,
+) -> Option<AccessPoint> {
+    let bssid = find_string(dict, "BSSID")?;
+    let channel = find_i32(dict, "CHANNEL").and_then(|c| u32::try_from(c).ok());
+    let band = find_i32(dict, "CHANNEL_FLAGS").and_then(band);
+
+    Some(AccessPoint {
+        interface: interface.to_string(),
+        ssid: find_string(dict, "SSID_STR").filter(|ssid| !ssid.is_empty()),
+        associated: associated == Some(bssid.as_str()),
+        frequency: channel
+            .zip(band)
+            .map(|(channel, band)| frequency(channel, band)),
+        channel,
+        band,
+        rssi: find_i32(dict, "RSSI"),
+        security: security(dict),
+        bssid,
+    })
+}
+
 pub struct WiFi(Apple80211Ref);
 
 impl WiFi {
     pub fn new(interface: &str) -> Result<WiFi, Error> {
-        let handle: Apple80211Ref = std::ptr::null();
+        let mut handle: Apple80211Ref = std::ptr::null();
 
-        let res = unsafe { Apple80211Open(&handle) };
+        let res = unsafe { Apple80211Open(&mut handle) };
 
         if res == 0 {
             let name = CFString::new(interface);
@@ -148,8 +319,8 @@ impl WiFi {
     }
 
     pub fn on_power(&self) -> Result<bool, Error> {
-        let power: u32 = 0;
-        let res = unsafe { Apple80211GetPower(self.0, &power) };
+        let mut power: u32 = 0;
+        let res = unsafe { Apple80211GetPower(self.0, &mut power) };
 
         if res == 0 {
             Ok(power != 0)
@@ -165,6 +336,46 @@ impl WiFi {
     pub fn bssid(&self) -> Result<String, Error> {
         get_bssid(self.0)
     }
+
+    pub fn rssi(&self) -> Result<i32, Error> {
+        get_level(self.0, 16)
+    }
+
+    pub fn noise(&self) -> Result<i32, Error> {
+        get_level(self.0, 17)
+    }
+
+    pub fn channel(&self) -> Result<i32, Error> {
+        get_level(self.0, 4)
+    }
+
+    pub fn tx_rate(&self) -> Result<i32, Error> {
+        get_level(self.0, 8)
+    }
+
+    pub fn scan(&self, interface: &str) -> Result<Vec<AccessPoint>, Error> {
+        let mut list: CFArrayRef = std::ptr::null();
+        let parameters = CFDictionary::<CFString, CFType>::from_CFType_pairs(&[]);
+        let res = unsafe { Apple80211Scan(self.0, &mut list, parameters.as_concrete_TypeRef()) };
+
+        if res != 0 {
+            return Err(Error(res));
+        }
+        if list.is_null() {
+            return Ok(vec![]);
+        }
+
+        let associated = self.bssid().ok();
+        let list = unsafe { CFArray::<CFDictionaryRef>::wrap_under_create_rule(list) };
+
+        Ok(list
+            .iter()
+            .filter_map(|dict| {
+                let dict = unsafe { CFDictionary::<CFString, CFType>::wrap_under_get_rule(*dict) };
+                access_point(interface, associated.as_deref(), &dict)
+            })
+            .collect())
+    }
 }
 
 impl Drop for WiFi {
@@ -179,6 +390,8 @@ impl fmt::Debug for WiFi {
             .field("on_power", &self.on_power())
             .field("bssid", &self.bssid())
             .field("ssid", &self.ssid())
+            .field("rssi", &self.rssi())
+            .field("noise", &self.noise())
             .finish()
     }
 }
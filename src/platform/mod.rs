@@ -5,6 +5,9 @@ cfg_if! {
     if #[cfg(target_os = "macos")] {
         mod macos;
         pub use macos::imp;
+    } else if #[cfg(target_os = "linux")] {
+        mod linux;
+        pub use linux::imp;
     } else if #[cfg(windows)] {
         mod windows;
         pub use windows::imp;
@@ -1,11 +1,11 @@
-use crate::types::cpu::LoadAvg;
+use crate::types::{cpu::LoadAvg, Error, Result};
 use libc::getloadavg;
 
-pub fn loadavg() -> Option<LoadAvg> {
+pub fn loadavg() -> Result<LoadAvg> {
     let mut res = Default::default();
     if unsafe { getloadavg(&mut res as *mut _ as *mut f64, 3) } == -1 {
-        None
+        Err(Error::not_found("Load average not available"))
     } else {
-        Some(res)
+        Ok(res)
     }
 }
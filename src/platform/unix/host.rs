@@ -0,0 +1,64 @@
+use crate::types::{Error, Result};
+use libc::{c_char, getdomainname, uname, utsname};
+use std::ffi::CStr;
+use std::path::Path;
+
+pub struct Uname {
+    pub sysname: String,
+    pub nodename: String,
+    pub release: String,
+    pub machine: String,
+}
+
+fn c_str_to_string(s: &[c_char]) -> String {
+    unsafe { CStr::from_ptr(s.as_ptr()) }
+        .to_string_lossy()
+        .trim()
+        .to_string()
+}
+
+pub fn get_uname() -> Result<Uname> {
+    let mut res: utsname = unsafe { std::mem::zeroed() };
+
+    if unsafe { uname(&mut res) } == -1 {
+        Err(Error::from_errno())
+    } else {
+        Ok(Uname {
+            sysname: c_str_to_string(&res.sysname),
+            nodename: c_str_to_string(&res.nodename),
+            release: c_str_to_string(&res.release),
+            machine: c_str_to_string(&res.machine),
+        })
+    }
+}
+
+pub fn domainname() -> Option<String> {
+    let mut buf: [c_char; 256] = [0; 256];
+
+    if unsafe { getdomainname(buf.as_mut_ptr(), (buf.len() - 1) as _) } == -1 {
+        return None;
+    }
+
+    let name = c_str_to_string(&buf);
+    if name.is_empty() || name == "(none)" {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+pub fn timezone() -> Option<String> {
+    if let Ok(tz) = std::env::var("TZ") {
+        let tz = tz.trim_start_matches(':');
+        if !tz.is_empty() {
+            return Some(tz.to_string());
+        }
+    }
+
+    let target = std::fs::read_link(Path::new("/etc/localtime")).ok()?;
+    let target = target.to_string_lossy();
+
+    target
+        .find("zoneinfo/")
+        .map(|i| target[i + "zoneinfo/".len()..].to_string())
+}
@@ -0,0 +1,170 @@
+use crate::types::network::Address;
+use crate::types::{Error, Result};
+use libc::{
+    freeifaddrs, getifaddrs, ifaddrs, sockaddr, sockaddr_in, sockaddr_in6, AF_INET, AF_INET6,
+};
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[derive(Debug, Clone, Default)]
+pub struct IfAddrs {
+    pub mac: Option<String>,
+    pub addresses: Vec<Address>,
+}
+
+fn format_mac(bytes: &[u8]) -> Option<String> {
+    if bytes.is_empty() || bytes.iter().all(|b| *b == 0) {
+        None
+    } else {
+        Some(
+            bytes
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<Vec<_>>()
+                .join(":"),
+        )
+    }
+}
+
+unsafe fn sockaddr_ip(addr: *const sockaddr) -> Option<IpAddr> {
+    if addr.is_null() {
+        return None;
+    }
+
+    match i32::from((*addr).sa_family) {
+        AF_INET => {
+            let addr = &*(addr as *const sockaddr_in);
+            Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(
+                addr.sin_addr.s_addr,
+            ))))
+        }
+        AF_INET6 => {
+            let addr = &*(addr as *const sockaddr_in6);
+            Some(IpAddr::V6(Ipv6Addr::from(addr.sin6_addr.s6_addr)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+unsafe fn sockaddr_len(addr: *const sockaddr, _max: usize) -> usize {
+    (*addr).sa_len as usize
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+unsafe fn sockaddr_len(_addr: *const sockaddr, max: usize) -> usize {
+    max
+}
+
+// The netmask is decoded with the family of the address it belongs to: BSD
+// leaves its sa_family at 0 and truncates sa_len after the last non-zero byte.
+unsafe fn prefix(netmask: *const sockaddr, ip: &IpAddr) -> u8 {
+    if netmask.is_null() {
+        return 0;
+    }
+
+    let (offset, len, size) = match ip {
+        IpAddr::V4(_) => (4, 4, std::mem::size_of::<sockaddr_in>()),
+        IpAddr::V6(_) => (8, 16, std::mem::size_of::<sockaddr_in6>()),
+    };
+    let end = sockaddr_len(netmask, size).min(offset + len);
+    if end <= offset {
+        return 0;
+    }
+
+    std::slice::from_raw_parts((netmask as *const u8).add(offset), end - offset)
+        .iter()
+        .map(|b| b.count_ones() as u8)
+        .sum()
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn sockaddr_mac(addr: *const sockaddr) -> Option<String> {
+    if addr.is_null() || i32::from((*addr).sa_family) != libc::AF_PACKET {
+        return None;
+    }
+
+    let addr = &*(addr as *const libc::sockaddr_ll);
+    let len = (addr.sll_halen as usize).min(addr.sll_addr.len());
+    format_mac(&addr.sll_addr[..len])
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+unsafe fn sockaddr_mac(addr: *const sockaddr) -> Option<String> {
+    if addr.is_null() || i32::from((*addr).sa_family) != libc::AF_LINK {
+        return None;
+    }
+
+    let addr = addr as *const libc::sockaddr_dl;
+    let data = (*addr).sdl_data.as_ptr() as *const u8;
+    format_mac(std::slice::from_raw_parts(
+        data.add((*addr).sdl_nlen as usize),
+        (*addr).sdl_alen as usize,
+    ))
+}
+
+pub fn all() -> Result<HashMap<String, IfAddrs>> {
+    let mut head: *mut ifaddrs = std::ptr::null_mut();
+
+    if unsafe { getifaddrs(&mut head) } == -1 {
+        return Err(Error::from_errno());
+    }
+
+    let mut res: HashMap<String, IfAddrs> = HashMap::new();
+    let mut cur = head;
+    while !cur.is_null() {
+        let ifa = unsafe { &*cur };
+        cur = ifa.ifa_next;
+
+        if ifa.ifa_name.is_null() {
+            continue;
+        }
+
+        let name = unsafe { CStr::from_ptr(ifa.ifa_name) }
+            .to_string_lossy()
+            .into_owned();
+        let entry = res.entry(name).or_default();
+
+        if let Some(ip) = unsafe { sockaddr_ip(ifa.ifa_addr) } {
+            entry.addresses.push(Address {
+                ip,
+                prefix: unsafe { prefix(ifa.ifa_netmask, &ip) },
+            });
+        } else if let Some(mac) = unsafe { sockaddr_mac(ifa.ifa_addr) } {
+            entry.mac = Some(mac);
+        }
+    }
+
+    unsafe { freeifaddrs(head) };
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod test {
+    use super::prefix;
+    use libc::{sockaddr, sockaddr_in, sockaddr_in6};
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn netmask_prefix() {
+        let mut mask: sockaddr_in = unsafe { std::mem::zeroed() };
+        mask.sin_addr.s_addr = u32::from(Ipv4Addr::new(255, 255, 255, 0)).to_be();
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2));
+        assert_eq!(
+            unsafe { prefix(&mask as *const _ as *const sockaddr, &ip) },
+            24
+        );
+
+        let mut mask: sockaddr_in6 = unsafe { std::mem::zeroed() };
+        mask.sin6_addr.s6_addr[..8].copy_from_slice(&[0xff; 8]);
+        let ip = IpAddr::V6(Ipv6Addr::LOCALHOST);
+        assert_eq!(
+            unsafe { prefix(&mask as *const _ as *const sockaddr, &ip) },
+            64
+        );
+
+        assert_eq!(unsafe { prefix(std::ptr::null(), &ip) }, 0);
+    }
+}
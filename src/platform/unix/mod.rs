@@ -1 +1,5 @@
 pub mod cpu;
+pub mod host;
+pub mod ifaddrs;
+pub mod resolv;
+pub mod users;
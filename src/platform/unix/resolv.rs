@@ -0,0 +1,63 @@
+use crate::types::network::Dns;
+use crate::types::{Error, Result};
+use std::net::IpAddr;
+
+pub const RESOLV_CONF: &str = "/etc/resolv.conf";
+
+pub fn parse_resolv_conf(content: &str) -> Dns {
+    let mut res = Dns::default();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        let mut fields = line.split_whitespace();
+        match fields.next() {
+            Some("nameserver") => {
+                if let Some(ip) = fields
+                    .next()
+                    .and_then(|ip| ip.split('%').next())
+                    .and_then(|ip| ip.parse::<IpAddr>().ok())
+                {
+                    res.nameservers.push(ip);
+                }
+            }
+            Some("search") => res.search = fields.map(str::to_string).collect(),
+            Some("domain") => {
+                if let Some(domain) = fields.next() {
+                    res.search = vec![domain.to_string()];
+                }
+            }
+            Some("options") => res.options.extend(fields.map(str::to_string)),
+            _ => {}
+        }
+    }
+
+    res
+}
+
+pub fn dns() -> Result<Dns> {
+    let content = std::fs::read_to_string(RESOLV_CONF).map_err(Error::from)?;
+
+    Ok(parse_resolv_conf(&content))
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_resolv_conf;
+
+    #[test]
+    fn resolv_conf() {
+        let dns = parse_resolv_conf(
+            "# generated\nnameserver 127.0.0.53\nnameserver fe80::1%eth0\nsearch example.com corp.example.com\noptions edns0 trust-ad\n",
+        );
+
+        assert_eq!(dns.nameservers.len(), 2);
+        assert_eq!(dns.nameservers[0].to_string(), "127.0.0.53");
+        assert_eq!(dns.nameservers[1].to_string(), "fe80::1");
+        assert_eq!(dns.search, vec!["example.com", "corp.example.com"]);
+        assert_eq!(dns.options, vec!["edns0", "trust-ad"]);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::types::users::Session;
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const USER_PROCESS: i16 = 7;
+
+pub fn field_i16(buf: &[u8], offset: usize) -> i16 {
+    let mut bytes = [0u8; 2];
+    bytes.copy_from_slice(&buf[offset..offset + 2]);
+    i16::from_ne_bytes(bytes)
+}
+
+pub fn field_i32(buf: &[u8], offset: usize) -> i32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&buf[offset..offset + 4]);
+    i32::from_ne_bytes(bytes)
+}
+
+pub fn field_string(buf: &[u8], offset: usize, len: usize) -> String {
+    let field = &buf[offset..offset + len];
+    let end = field.iter().position(|b| *b == 0).unwrap_or(len);
+    String::from_utf8_lossy(&field[..end]).trim().to_string()
+}
+
+pub fn login_time(sec: i64, usec: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::new(sec.max(0) as u64, (usec.max(0) as u32).min(999_999) * 1_000)
+}
+
+pub fn idle(tty: &str) -> Option<Duration> {
+    let atime = std::fs::metadata(Path::new("/dev").join(tty)).ok()?.atime();
+    let atime = UNIX_EPOCH + Duration::from_secs(atime.max(0) as u64);
+
+    Some(SystemTime::now().duration_since(atime).unwrap_or_default())
+}
+
+pub fn with_idle(mut sessions: Vec<Session>) -> Vec<Session> {
+    for session in sessions.iter_mut() {
+        session.idle = idle(&session.tty);
+    }
+
+    sessions
+}
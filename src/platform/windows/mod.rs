@@ -2,12 +2,20 @@ pub mod prelude {
     pub use crate::types::{
         cpu::{CoresLoadInfo, LoadAvg},
         fs::FileSystem,
+        kernel::KernelActivity,
+        limits::Limits,
         mem::{Swap, RAM},
-        network::NetworkInterface,
-        power::{PowerSources, Type},
+        misc::{Environment, Host},
+        network::{Dns, Link, NetworkInterface, Route},
+        power::PowerSources,
+        protocols::ProtocolCounters,
+        sockets::{Socket, TcpState},
         thermal::Fans,
+        users::Session,
+        wifi::AccessPoint,
         Error, Result,
     };
+    pub use std::collections::HashMap;
     pub use std::time::SystemTime;
 }
 
@@ -15,68 +23,128 @@ pub mod imp {
     pub mod network {
         use crate::platform::windows::prelude::*;
         pub fn all() -> Result<Vec<NetworkInterface>> {
-            Ok(vec![])
+            Err(Error::unsupported("Network interfaces unsupported"))
+        }
+        pub fn topology() -> Result<Vec<Link>> {
+            Err(Error::unsupported("Network topology unsupported"))
+        }
+        pub fn routes() -> Result<Vec<Route>> {
+            Err(Error::unsupported("Routing table unsupported"))
+        }
+        pub fn dns() -> Result<Dns> {
+            Err(Error::unsupported("DNS configuration unsupported"))
         }
     }
 
     pub mod cpu {
         use crate::platform::windows::prelude::*;
         pub fn load() -> Result<CoresLoadInfo> {
-            Ok(vec![])
+            Err(Error::unsupported("CPU load unsupported"))
         }
-        pub fn loadavg() -> Option<LoadAvg> {
-            None
+        pub fn loadavg() -> Result<LoadAvg> {
+            Err(Error::unsupported("Load average unsupported"))
         }
     }
     pub mod fs {
         use crate::platform::windows::prelude::*;
         pub fn all() -> Result<Vec<FileSystem>> {
-            Ok(vec![])
+            Err(Error::unsupported("Filesystems unsupported"))
         }
     }
 
     pub mod mem {
         use crate::platform::windows::prelude::*;
         pub fn ram() -> Result<RAM> {
-            Ok(RAM { used: 0, total: 0 })
+            Err(Error::unsupported("Memory statistics unsupported"))
         }
         pub fn swap() -> Result<Swap> {
-            Ok(Swap {
-                used: 0,
-                free: 0,
-                total: 0,
-            })
+            Err(Error::unsupported("Swap statistics unsupported"))
         }
     }
 
     pub mod power {
         use crate::platform::windows::prelude::*;
-        pub fn sources() -> PowerSources {
-            PowerSources {
-                sources: None,
-                power_type: Type::AC,
-                adapter: None,
-            }
+        pub fn sources() -> Result<PowerSources> {
+            Err(Error::unsupported("Power sources unsupported"))
         }
     }
 
     pub mod thermal {
         use crate::platform::windows::prelude::*;
-        pub fn cpus() -> Option<Vec<f64>> {
-            None
+        pub fn cpus() -> Result<Vec<f64>> {
+            Err(Error::unsupported("CPU temperature sensors unsupported"))
         }
-        pub fn custom(_key: &str) -> Option<f64> {
-            None
+        pub fn custom(_key: &str) -> Result<f64> {
+            Err(Error::unsupported("Custom sensors unsupported"))
         }
-        pub fn fans() -> Option<Fans> {
-            None
+        pub fn fans() -> Result<Fans> {
+            Err(Error::unsupported("Fan sensors unsupported"))
         }
     }
 
     pub mod boottime {
         use crate::platform::windows::prelude::*;
-        pub fn get() -> Option<SystemTime> {
-            None
+        pub fn get() -> Result<SystemTime> {
+            Err(Error::unsupported("Boot time unsupported"))
+        }
+    }
+
+    pub mod environment {
+        use crate::platform::windows::prelude::*;
+        pub fn get() -> Environment {
+            Default::default()
+        }
+    }
+
+    pub mod host {
+        use crate::platform::windows::prelude::*;
+        pub fn get() -> Result<Host> {
+            Err(Error::unsupported("Host information unsupported"))
+        }
+    }
+
+    pub mod users {
+        use crate::platform::windows::prelude::*;
+        pub fn sessions() -> Result<Vec<Session>> {
+            Err(Error::unsupported("User sessions unsupported"))
+        }
+    }
+
+    pub mod kernel {
+        use crate::platform::windows::prelude::*;
+        pub fn activity() -> Result<KernelActivity> {
+            Err(Error::unsupported("Kernel activity counters unsupported"))
+        }
+    }
+
+    pub mod limits {
+        use crate::platform::windows::prelude::*;
+        pub fn get() -> Result<Limits> {
+            Err(Error::unsupported("System limits unsupported"))
+        }
+    }
+
+    pub mod sockets {
+        use crate::platform::windows::prelude::*;
+        pub fn all() -> Result<Vec<Socket>> {
+            Err(Error::unsupported("Sockets unsupported"))
+        }
+        pub fn tcp_summary() -> Result<HashMap<TcpState, usize>> {
+            Err(Error::unsupported("Sockets unsupported"))
+        }
+    }
+
+    pub mod protocols {
+        use crate::platform::windows::prelude::*;
+        pub fn counters() -> Result<ProtocolCounters> {
+            Err(Error::unsupported("Protocol counters unsupported"))
+        }
+    }
+
+    pub mod wifi {
+        use crate::platform::windows::prelude::*;
+        pub fn scan() -> Result<Vec<AccessPoint>> {
+            Err(Error::unsupported("WiFi scanning unsupported"))
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::platform::imp::power;
 use crate::types::power::PowerSources;
+use crate::types::Result;
 
 pub struct Power;
 
@@ -8,7 +9,7 @@ impl Power {
         Power
     }
 
-    pub fn sources(&self) -> PowerSources {
+    pub fn sources(&self) -> Result<PowerSources> {
         power::sources()
     }
 }
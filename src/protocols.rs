@@ -0,0 +1,231 @@
+use crate::history::{self, History, Retention};
+use crate::perfecter::{Perfecter, PerfecterProvider};
+use crate::platform::imp::protocols;
+use crate::timer::Sampler;
+use crate::types::protocols::{
+    IpCounters, IpRates, ProtocolCounters, ProtocolRates, TcpCounters, TcpRates, UdpCounters,
+    UdpRates,
+};
+use crate::types::rate::PerSecond;
+use crate::types::{Error, Result};
+use crate::Timer;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Clone, Default)]
+pub(crate) struct State {
+    prev: Option<ProtocolCounters>,
+    pub(crate) current: Option<ProtocolCounters>,
+    rates: Option<ProtocolRates>,
+    error: Option<Error>,
+}
+
+pub struct Protocols {
+    timer: Timer<Option<State>>,
+    history: Arc<Mutex<History<ProtocolCounters>>>,
+}
+
+fn deltas(
+    perfecter: &Perfecter,
+    next: &ProtocolCounters,
+    prev: &ProtocolCounters,
+) -> ProtocolCounters {
+    let delta = |n: u64, p: u64| perfecter.perfect(&n.saturating_sub(p));
+    let (ni, pi) = (&next.ip, &prev.ip);
+    let (nt, pt) = (&next.tcp, &prev.tcp);
+    let (nu, pu) = (&next.udp, &prev.udp);
+
+    ProtocolCounters {
+        ip: IpCounters {
+            in_receives: delta(ni.in_receives, pi.in_receives),
+            in_delivers: delta(ni.in_delivers, pi.in_delivers),
+            in_discards: delta(ni.in_discards, pi.in_discards),
+            in_hdr_errors: delta(ni.in_hdr_errors, pi.in_hdr_errors),
+            forwarded: delta(ni.forwarded, pi.forwarded),
+            out_requests: delta(ni.out_requests, pi.out_requests),
+            out_discards: delta(ni.out_discards, pi.out_discards),
+            out_no_routes: delta(ni.out_no_routes, pi.out_no_routes),
+        },
+        tcp: TcpCounters {
+            active_opens: delta(nt.active_opens, pt.active_opens),
+            passive_opens: delta(nt.passive_opens, pt.passive_opens),
+            attempt_fails: delta(nt.attempt_fails, pt.attempt_fails),
+            estab_resets: delta(nt.estab_resets, pt.estab_resets),
+            curr_estab: nt.curr_estab,
+            in_segs: delta(nt.in_segs, pt.in_segs),
+            out_segs: delta(nt.out_segs, pt.out_segs),
+            retrans_segs: delta(nt.retrans_segs, pt.retrans_segs),
+            in_errs: delta(nt.in_errs, pt.in_errs),
+            out_rsts: delta(nt.out_rsts, pt.out_rsts),
+            listen_overflows: delta(nt.listen_overflows, pt.listen_overflows),
+            listen_drops: delta(nt.listen_drops, pt.listen_drops),
+        },
+        udp: UdpCounters {
+            in_datagrams: delta(nu.in_datagrams, pu.in_datagrams),
+            out_datagrams: delta(nu.out_datagrams, pu.out_datagrams),
+            no_ports: delta(nu.no_ports, pu.no_ports),
+            in_errors: delta(nu.in_errors, pu.in_errors),
+            rcvbuf_errors: delta(nu.rcvbuf_errors, pu.rcvbuf_errors),
+            sndbuf_errors: delta(nu.sndbuf_errors, pu.sndbuf_errors),
+        },
+    }
+}
+
+fn rates(elapsed: Duration, next: &ProtocolCounters, prev: &ProtocolCounters) -> ProtocolRates {
+    let count = |n: u64, p: u64| PerSecond::from_delta(n.saturating_sub(p), elapsed);
+    let (ni, pi) = (&next.ip, &prev.ip);
+    let (nt, pt) = (&next.tcp, &prev.tcp);
+    let (nu, pu) = (&next.udp, &prev.udp);
+
+    ProtocolRates {
+        ip: IpRates {
+            in_receives: count(ni.in_receives, pi.in_receives),
+            in_delivers: count(ni.in_delivers, pi.in_delivers),
+            in_discards: count(ni.in_discards, pi.in_discards),
+            in_hdr_errors: count(ni.in_hdr_errors, pi.in_hdr_errors),
+            forwarded: count(ni.forwarded, pi.forwarded),
+            out_requests: count(ni.out_requests, pi.out_requests),
+            out_discards: count(ni.out_discards, pi.out_discards),
+            out_no_routes: count(ni.out_no_routes, pi.out_no_routes),
+        },
+        tcp: TcpRates {
+            active_opens: count(nt.active_opens, pt.active_opens),
+            passive_opens: count(nt.passive_opens, pt.passive_opens),
+            attempt_fails: count(nt.attempt_fails, pt.attempt_fails),
+            estab_resets: count(nt.estab_resets, pt.estab_resets),
+            in_segs: count(nt.in_segs, pt.in_segs),
+            out_segs: count(nt.out_segs, pt.out_segs),
+            retrans_segs: count(nt.retrans_segs, pt.retrans_segs),
+            in_errs: count(nt.in_errs, pt.in_errs),
+            out_rsts: count(nt.out_rsts, pt.out_rsts),
+            listen_overflows: count(nt.listen_overflows, pt.listen_overflows),
+            listen_drops: count(nt.listen_drops, pt.listen_drops),
+        },
+        udp: UdpRates {
+            in_datagrams: count(nu.in_datagrams, pu.in_datagrams),
+            out_datagrams: count(nu.out_datagrams, pu.out_datagrams),
+            no_ports: count(nu.no_ports, pu.no_ports),
+            in_errors: count(nu.in_errors, pu.in_errors),
+            rcvbuf_errors: count(nu.rcvbuf_errors, pu.rcvbuf_errors),
+            sndbuf_errors: count(nu.sndbuf_errors, pu.sndbuf_errors),
+        },
+    }
+}
+
+pub(crate) fn sample(state: Option<State>, provider: Arc<PerfecterProvider>) -> Option<State> {
+    let state = state.unwrap_or_default();
+    let next_prev = match protocols::counters() {
+        Ok(counters) => counters,
+        Err(error) => {
+            return Some(State {
+                prev: state.prev,
+                current: None,
+                rates: None,
+                error: Some(error),
+            })
+        }
+    };
+
+    let (current, rates) = match (state.prev, provider.get()) {
+        (Some(prev), Some(perfecter)) => (
+            Some(deltas(&perfecter, &next_prev, &prev)),
+            Some(rates(*perfecter.actual_duration(), &next_prev, &prev)),
+        ),
+        _ => (None, None),
+    };
+
+    Some(State {
+        prev: Some(next_prev),
+        current,
+        rates,
+        error: None,
+    })
+}
+
+impl Protocols {
+    pub fn new() -> Protocols {
+        Protocols::with_interval(Duration::from_secs(1))
+    }
+
+    pub fn with_interval(interval: Duration) -> Protocols {
+        Protocols::from_timer(Timer::new(None, interval, sample))
+    }
+
+    pub fn with_sampler(sampler: &Sampler) -> Protocols {
+        Protocols::from_timer(Timer::shared(sampler, None, sample))
+    }
+
+    fn from_timer(timer: Timer<Option<State>>) -> Protocols {
+        let history = history::record(&timer, Retention::Count(0), |state| state.as_ref()?.current);
+
+        Protocols { timer, history }
+    }
+
+    pub fn counters(&self) -> Result<ProtocolCounters> {
+        let state = self.timer.get().unwrap_or_default();
+
+        match state.error {
+            Some(error) => Err(error),
+            None => state
+                .current
+                .ok_or_else(|| Error::not_ready("No protocol counters sample available yet")),
+        }
+    }
+
+    pub fn rates(&self) -> Result<ProtocolRates> {
+        let state = self.timer.get().unwrap_or_default();
+
+        match state.error {
+            Some(error) => Err(error),
+            None => state
+                .rates
+                .ok_or_else(|| Error::not_ready("No protocol counters sample available yet")),
+        }
+    }
+
+    pub fn totals(&self) -> Result<ProtocolCounters> {
+        let state = self.timer.get().unwrap_or_default();
+
+        match state.error {
+            Some(error) => Err(error),
+            None => state
+                .prev
+                .ok_or_else(|| Error::not_ready("No protocol counters sample available yet")),
+        }
+    }
+
+    pub fn last_error(&self) -> Option<Error> {
+        self.timer.get()?.error
+    }
+
+    pub fn history<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&History<ProtocolCounters>) -> R,
+    {
+        f(&self.history.lock().unwrap())
+    }
+
+    pub fn set_history_retention(&self, retention: Retention) {
+        self.history.lock().unwrap().set_retention(retention)
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.timer.interval()
+    }
+
+    pub fn set_interval(&self, interval: Duration) -> Result<()> {
+        self.timer.set_interval(interval)
+    }
+
+    pub fn close(self) {
+        let _ = self.timer.stop();
+        let _ = self.timer.join();
+    }
+}
+
+impl Default for Protocols {
+    fn default() -> Protocols {
+        Protocols::new()
+    }
+}
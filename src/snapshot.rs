@@ -0,0 +1,77 @@
+use crate::perfecter::PerfecterProvider;
+use crate::timer::stream::sampled;
+use crate::types::cpu::CoresLoadInfo;
+use crate::types::kernel::KernelActivity;
+use crate::types::network::NetworkInterface;
+use crate::types::protocols::ProtocolCounters;
+use crate::{cpu, kernel, network, protocols};
+use futures::{Stream, StreamExt};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub time: SystemTime,
+    pub cpu: Option<CoresLoadInfo>,
+    pub network: Option<Vec<NetworkInterface>>,
+    pub kernel: Option<KernelActivity>,
+    pub protocols: Option<ProtocolCounters>,
+}
+
+#[derive(Clone)]
+struct State {
+    cpu: Option<cpu::State>,
+    network: Option<network::State>,
+    kernel: Option<kernel::State>,
+    protocols: Option<protocols::State>,
+}
+
+impl Snapshot {
+    pub fn stream(interval: Duration) -> impl Stream<Item = Snapshot> + Send {
+        let providers = [(); 4].map(|_| Arc::new(PerfecterProvider::new(interval)));
+        let initial = State {
+            cpu: None,
+            network: None,
+            kernel: None,
+            protocols: None,
+        };
+
+        sampled(interval, initial, move |state| State {
+            cpu: cpu::sample(state.cpu, providers[0].clone()),
+            network: network::sample(state.network, providers[1].clone()),
+            kernel: kernel::sample(state.kernel, providers[2].clone()),
+            protocols: protocols::sample(state.protocols, providers[3].clone()),
+        })
+        .skip(1)
+        .map(|state| Snapshot {
+            time: SystemTime::now(),
+            cpu: state.cpu.and_then(|state| state.current),
+            network: state.network.and_then(|state| state.current),
+            kernel: state.kernel.and_then(|state| state.current),
+            protocols: state.protocols.and_then(|state| state.current),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Snapshot;
+    use futures::StreamExt;
+    use std::time::Duration;
+    use tokio::runtime::Builder;
+
+    #[test]
+    fn snapshot_stream() {
+        let runtime = Builder::new_current_thread().enable_time().build().unwrap();
+        let snapshots = runtime.block_on(
+            Snapshot::stream(Duration::from_millis(50))
+                .take(2)
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(snapshots.len(), 2);
+        assert!(snapshots[0].time <= snapshots[1].time);
+        #[cfg(target_os = "linux")]
+        assert!(snapshots[1].network.is_some());
+    }
+}
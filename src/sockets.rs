@@ -0,0 +1,26 @@
+use crate::platform::imp::sockets;
+use crate::types::sockets::{Socket, TcpState};
+use crate::types::Result;
+use std::collections::HashMap;
+
+pub struct Sockets;
+
+impl Sockets {
+    pub fn new() -> Sockets {
+        Sockets
+    }
+
+    pub fn all(&self) -> Result<Vec<Socket>> {
+        sockets::all()
+    }
+
+    pub fn tcp_summary(&self) -> Result<HashMap<TcpState, usize>> {
+        sockets::tcp_summary()
+    }
+}
+
+impl Default for Sockets {
+    fn default() -> Sockets {
+        Sockets::new()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::platform::imp::thermal;
 use crate::types::thermal::*;
+use crate::types::Result;
 
 pub struct Thermal;
 
@@ -8,15 +9,15 @@ impl Thermal {
         Thermal
     }
 
-    pub fn fans(&self) -> Option<Fans> {
+    pub fn fans(&self) -> Result<Fans> {
         thermal::fans()
     }
 
-    pub fn cpus(&self) -> Option<Vec<f64>> {
+    pub fn cpus(&self) -> Result<Vec<f64>> {
         thermal::cpus()
     }
 
-    pub fn custom(&self, key: &str) -> Option<f64> {
+    pub fn custom(&self, key: &str) -> Result<f64> {
         thermal::custom(key)
     }
 }
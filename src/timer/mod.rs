@@ -1,7 +1,11 @@
 pub mod perfected_stated_timer;
+pub mod sampler;
 pub mod stated_timer;
+#[cfg(feature = "async")]
+pub mod stream;
 pub mod timer;
 
 pub use perfected_stated_timer::PerfectedStatedTimer as Timer;
-pub use stated_timer::StatedTimer;
+pub use sampler::Sampler;
+pub use stated_timer::{Sample, StatedTimer};
 pub use timer::Timer as SimpleTimer;
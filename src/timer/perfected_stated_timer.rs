@@ -1,12 +1,16 @@
-use super::StatedTimer;
+use super::{Sample, Sampler, StatedTimer};
 use crate::perfecter::PerfecterProvider;
+use crate::types::Result;
 
-use std::sync::{mpsc::SendError, Arc};
+use std::sync::{
+    mpsc::{Receiver, SendError},
+    Arc,
+};
 use std::time::Duration;
 
 pub struct PerfectedStatedTimer<T: Clone + Send + 'static> {
     timer: StatedTimer<T>,
-    // provider: Arc<RwLock<PerfecterProvider>>
+    provider: Arc<PerfecterProvider>,
 }
 
 impl<T: Clone + Send + 'static> PerfectedStatedTimer<T> {
@@ -16,20 +20,54 @@ impl<T: Clone + Send + 'static> PerfectedStatedTimer<T> {
         F: Send + 'static,
     {
         let provider = Arc::new(PerfecterProvider::new(duration));
-        // let p = provider.clone();
+        let p = provider.clone();
 
         let timer = StatedTimer::new(initial, duration, move |state| f(state, provider.clone()));
 
-        PerfectedStatedTimer {
-            timer,
-            // provider: p,
-        }
+        PerfectedStatedTimer { timer, provider: p }
+    }
+
+    pub fn shared<F>(sampler: &Sampler, initial: T, f: F) -> Self
+    where
+        F: Fn(T, Arc<PerfecterProvider>) -> T,
+        F: Send + Sync + 'static,
+    {
+        let provider = Arc::new(PerfecterProvider::new(sampler.interval()));
+        let p = provider.clone();
+        let interval = sampler.timer().interval_handle();
+
+        let timer = StatedTimer::shared(sampler, initial, move |state| {
+            provider.set_expected_duration(*interval.lock().unwrap());
+            f(state, provider.clone())
+        });
+
+        PerfectedStatedTimer { timer, provider: p }
     }
 
     pub fn get(&self) -> T {
         self.timer.get()
     }
 
+    pub fn subscribe<F>(&self, f: F)
+    where
+        F: FnMut(&Sample<T>),
+        F: Send + 'static,
+    {
+        self.timer.subscribe(f)
+    }
+
+    pub(crate) fn subscribe_while<F>(&self, f: F)
+    where
+        F: FnMut(&Sample<T>) -> bool,
+        F: Send + 'static,
+    {
+        self.timer.subscribe_while(f)
+    }
+
+    pub fn watch(&self) -> Receiver<Sample<T>> {
+        self.timer.watch()
+    }
+
     pub fn join(self) -> std::thread::Result<()> {
         self.timer.join()
     }
@@ -38,11 +76,28 @@ impl<T: Clone + Send + 'static> PerfectedStatedTimer<T> {
         self.timer.thread()
     }
 
-    pub fn stop(&self) -> Result<(), SendError<()>> {
+    pub fn stop(&self) -> std::result::Result<(), SendError<()>> {
         self.timer.stop()
     }
 
     pub fn is_running(&self) -> bool {
         self.timer.is_running()
     }
+
+    pub fn interval(&self) -> Duration {
+        self.timer.interval()
+    }
+
+    pub fn set_interval(&self, interval: Duration) -> Result<()> {
+        // The provider has to know the new interval before the worker wakes up,
+        // or a tick that is already due gets scaled to the old one.
+        let previous = self.provider.expected_duration();
+        self.provider.set_expected_duration(interval);
+
+        let res = self.timer.set_interval(interval);
+        if res.is_err() {
+            self.provider.set_expected_duration(previous);
+        }
+        res
+    }
 }
@@ -0,0 +1,59 @@
+use super::timer::{Tasks, Timer};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+pub struct Sampler {
+    tasks: Tasks,
+    timer: Timer,
+}
+
+impl Sampler {
+    pub fn new(interval: Duration) -> Sampler {
+        let tasks: Tasks = Arc::new(Mutex::new(Vec::new()));
+        let t = tasks.clone();
+
+        let timer = Timer::new(interval, move || {
+            let tasks = t
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(_, task)| task.clone())
+                .collect::<Vec<_>>();
+
+            for task in tasks {
+                task();
+            }
+        });
+
+        Sampler { tasks, timer }
+    }
+
+    pub(crate) fn tasks(&self) -> Tasks {
+        self.tasks.clone()
+    }
+
+    pub(crate) fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    pub fn thread(&self) -> &std::thread::Thread {
+        self.timer.thread()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.timer.is_running()
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.timer.interval()
+    }
+
+    pub fn set_interval(&self, interval: Duration) {
+        let _ = self.timer.set_interval(interval);
+    }
+
+    pub fn close(self) {
+        let _ = self.timer.stop();
+        let _ = self.timer.join();
+    }
+}
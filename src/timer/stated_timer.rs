@@ -1,32 +1,107 @@
 use super::timer::Timer;
-use std::sync::{mpsc::SendError, Arc, Mutex};
-use std::time::Duration;
+use super::Sampler;
+use crate::types::Result;
+use std::mem;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    mpsc::{channel, Receiver, SendError},
+    Arc, Mutex,
+};
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct Sample<T> {
+    pub seq: u64,
+    pub timestamp: SystemTime,
+    pub value: T,
+}
+
+impl<T> Sample<T> {
+    pub fn and_then<U, F>(&self, f: F) -> Option<Sample<U>>
+    where
+        F: FnOnce(&T) -> Option<U>,
+    {
+        Some(Sample {
+            seq: self.seq,
+            timestamp: self.timestamp,
+            value: f(&self.value)?,
+        })
+    }
+}
+
+type Subscribers<T> = Arc<Mutex<Vec<Box<dyn FnMut(&Sample<T>) -> bool + Send>>>>;
 
 pub struct StatedTimer<T: Clone + Send + 'static> {
     state: Arc<Mutex<T>>,
+    subscribers: Subscribers<T>,
     timer: Timer,
 }
 
+fn tick<T, F>(state: Arc<Mutex<T>>, subscribers: Subscribers<T>, f: F) -> impl Fn() + Send + 'static
+where
+    T: Clone + Send + 'static,
+    F: Fn(T) -> T,
+    F: Send + 'static,
+{
+    let seq = AtomicU64::new(0);
+
+    move || {
+        let s = f({ state.lock().unwrap().clone() });
+        let seq = seq.fetch_add(1, Ordering::SeqCst) + 1;
+        let mut current = mem::take(&mut *subscribers.lock().unwrap());
+
+        if current.is_empty() {
+            let mut bs = state.lock().unwrap();
+            *bs = s;
+        } else {
+            {
+                let mut bs = state.lock().unwrap();
+                *bs = s.clone();
+            }
+
+            let sample = Sample {
+                seq,
+                timestamp: SystemTime::now(),
+                value: s,
+            };
+            current.retain_mut(|f| f(&sample));
+
+            let mut subscribers = subscribers.lock().unwrap();
+            let added = mem::replace(&mut *subscribers, current);
+            subscribers.extend(added);
+        }
+    }
+}
+
 impl<T: Clone + Send + 'static> StatedTimer<T> {
     pub fn new<F>(initial: T, duration: Duration, f: F) -> Self
     where
         F: Fn(T) -> T,
         F: Send + 'static,
     {
-        let res_state = Arc::new(Mutex::new(initial));
-        let state = res_state.clone();
+        let state = Arc::new(Mutex::new(initial));
+        let subscribers: Subscribers<T> = Arc::new(Mutex::new(Vec::new()));
+        let timer = Timer::new(duration, tick(state.clone(), subscribers.clone(), f));
 
-        let timer = Timer::new(duration, move || {
-            let s = f({ state.lock().unwrap().clone() });
+        StatedTimer {
+            state,
+            subscribers,
+            timer,
+        }
+    }
 
-            {
-                let mut bs = state.lock().unwrap();
-                *bs = s;
-            }
-        });
+    pub fn shared<F>(sampler: &Sampler, initial: T, f: F) -> Self
+    where
+        F: Fn(T) -> T,
+        F: Send + Sync + 'static,
+    {
+        let state = Arc::new(Mutex::new(initial));
+        let subscribers: Subscribers<T> = Arc::new(Mutex::new(Vec::new()));
+        let timer = Timer::shared(sampler, tick(state.clone(), subscribers.clone(), f));
 
         StatedTimer {
-            state: res_state,
+            state,
+            subscribers,
             timer,
         }
     }
@@ -35,6 +110,31 @@ impl<T: Clone + Send + 'static> StatedTimer<T> {
         self.state.lock().unwrap().clone()
     }
 
+    pub fn subscribe<F>(&self, mut f: F)
+    where
+        F: FnMut(&Sample<T>),
+        F: Send + 'static,
+    {
+        self.subscribe_while(move |sample| {
+            f(sample);
+            true
+        })
+    }
+
+    pub(crate) fn subscribe_while<F>(&self, f: F)
+    where
+        F: FnMut(&Sample<T>) -> bool,
+        F: Send + 'static,
+    {
+        self.subscribers.lock().unwrap().push(Box::new(f));
+    }
+
+    pub fn watch(&self) -> Receiver<Sample<T>> {
+        let (tx, rx) = channel();
+        self.subscribe_while(move |sample| tx.send(sample.clone()).is_ok());
+        rx
+    }
+
     pub fn join(self) -> std::thread::Result<()> {
         self.timer.join()
     }
@@ -43,14 +143,61 @@ impl<T: Clone + Send + 'static> StatedTimer<T> {
         self.timer.thread()
     }
 
-    pub fn stop(&self) -> Result<(), SendError<()>> {
+    pub fn stop(&self) -> std::result::Result<(), SendError<()>> {
         self.timer.stop()
     }
 
     pub fn is_running(&self) -> bool {
         self.timer.is_running()
     }
+
+    pub fn interval(&self) -> Duration {
+        self.timer.interval()
+    }
+
+    pub fn set_interval(&self, interval: Duration) -> Result<()> {
+        self.timer.set_interval(interval)
+    }
 }
 
 unsafe impl<T: Clone + Send + 'static> Send for StatedTimer<T> {}
 unsafe impl<T: Clone + Send + 'static> Sync for StatedTimer<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::StatedTimer;
+    use std::sync::{mpsc::channel, Arc, Mutex};
+    use std::time::Duration;
+
+    #[test]
+    fn watch_sequence() {
+        let timer = StatedTimer::new(0u32, Duration::from_millis(10), |n| n + 1);
+        let rx = timer.watch();
+
+        let first = rx.recv().unwrap();
+        let second = rx.recv().unwrap();
+        assert_eq!(second.seq, first.seq + 1);
+        assert_eq!(second.value, first.value + 1);
+        assert!(second.timestamp >= first.timestamp);
+    }
+
+    #[test]
+    fn subscribe_from_callback() {
+        let timer = Arc::new(StatedTimer::new(0u32, Duration::from_millis(10), |n| n + 1));
+        let (tx, rx) = channel();
+        let tx = Mutex::new(tx);
+        let t = timer.clone();
+
+        timer.subscribe_while(move |_| {
+            drop(t.watch());
+            false
+        });
+        timer.subscribe(move |sample| {
+            let _ = tx.lock().unwrap().send(sample.seq);
+        });
+
+        let first = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        let second = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(second, first + 1);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::perfecter::PerfecterProvider;
+use futures::stream::{self, Stream};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::spawn_blocking;
+use tokio::time::{interval, MissedTickBehavior};
+
+pub fn sampled<T, F>(duration: Duration, initial: T, f: F) -> impl Stream<Item = T> + Send
+where
+    T: Clone + Send + 'static,
+    F: Fn(T) -> T,
+    F: Send + Sync + 'static,
+{
+    stream::unfold(
+        (None, initial, Arc::new(f)),
+        move |(ticker, state, f)| async move {
+            let mut ticker = ticker.unwrap_or_else(|| {
+                let mut ticker = interval(duration);
+                ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                ticker
+            });
+            ticker.tick().await;
+            let g = f.clone();
+            let state = spawn_blocking(move || g(state)).await.ok()?;
+            Some((state.clone(), (Some(ticker), state, f)))
+        },
+    )
+}
+
+pub fn perfected<T, F>(duration: Duration, initial: T, f: F) -> impl Stream<Item = T> + Send
+where
+    T: Clone + Send + 'static,
+    F: Fn(T, Arc<PerfecterProvider>) -> T,
+    F: Send + Sync + 'static,
+{
+    let provider = Arc::new(PerfecterProvider::new(duration));
+
+    sampled(duration, initial, move |state| f(state, provider.clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::perfected;
+    use futures::StreamExt;
+    use std::time::Duration;
+    use tokio::runtime::Builder;
+
+    #[test]
+    fn perfected_stream() {
+        let runtime = Builder::new_current_thread().enable_time().build().unwrap();
+        let items = runtime.block_on(
+            perfected(Duration::from_millis(10), 0u32, |n, _| n + 1)
+                .take(2)
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(items, vec![1, 2]);
+    }
+}
@@ -1,12 +1,33 @@
+use super::Sampler;
+use crate::types::{Error, Result};
 use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    mpsc::{channel, SendError, Sender},
-    Arc,
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    mpsc::{channel, RecvTimeoutError, SendError, Sender},
+    Arc, Mutex,
 };
-use std::thread::{sleep, spawn, JoinHandle};
+use std::thread::{current, spawn, JoinHandle, Thread};
 use std::time::{Duration, Instant};
 
-pub struct Timer(JoinHandle<()>, Sender<()>, Arc<AtomicBool>);
+pub(crate) type Tasks = Arc<Mutex<Vec<(usize, Arc<dyn Fn() + Send + Sync>)>>>;
+
+static NEXT_TASK: AtomicUsize = AtomicUsize::new(0);
+
+enum Signal {
+    Stop,
+    Interval,
+}
+
+enum Inner {
+    Thread(
+        Option<JoinHandle<()>>,
+        Sender<Signal>,
+        Arc<AtomicBool>,
+        Arc<Mutex<Duration>>,
+    ),
+    Shared(Tasks, usize, Thread, Arc<AtomicBool>, Arc<Mutex<Duration>>),
+}
+
+pub struct Timer(Inner);
 
 impl Timer {
     pub fn new<F>(time: Duration, f: F) -> Timer
@@ -17,6 +38,8 @@ impl Timer {
         let (tx, rx) = channel();
         let run = Arc::new(AtomicBool::new(false));
         let running = run.clone();
+        let res_interval = Arc::new(Mutex::new(time));
+        let interval = res_interval.clone();
 
         let handle = spawn(move || {
             running.store(true, Ordering::SeqCst);
@@ -24,46 +47,180 @@ impl Timer {
             loop {
                 f();
 
-                match rx.try_recv() {
-                    Ok(_) => {
-                        running.store(false, Ordering::SeqCst);
-                        return ();
-                    }
-                    Err(_) => {}
-                };
-
-                sleep({
-                    let dur = Instant::now() - last_start;
-                    if dur >= time {
-                        Duration::new(0, 0)
-                    } else {
-                        time - dur
-                    }
-                });
+                loop {
+                    let timeout = {
+                        let time = *interval.lock().unwrap();
+                        let dur = Instant::now() - last_start;
+                        if dur >= time {
+                            Duration::new(0, 0)
+                        } else {
+                            time - dur
+                        }
+                    };
+
+                    match rx.recv_timeout(timeout) {
+                        Err(RecvTimeoutError::Timeout) => break,
+                        Ok(Signal::Interval) => {}
+                        _ => {
+                            running.store(false, Ordering::SeqCst);
+                            return;
+                        }
+                    };
+                }
 
                 last_start = Instant::now();
             }
         });
 
-        Timer(handle, tx, run)
+        Timer(Inner::Thread(Some(handle), tx, run, res_interval))
+    }
+
+    pub fn shared<F>(sampler: &Sampler, f: F) -> Timer
+    where
+        F: Fn(),
+        F: Send + Sync + 'static,
+    {
+        let id = NEXT_TASK.fetch_add(1, Ordering::SeqCst);
+        let tasks = sampler.tasks();
+        tasks.lock().unwrap().push((id, Arc::new(f)));
+
+        Timer(Inner::Shared(
+            tasks,
+            id,
+            sampler.thread().clone(),
+            sampler.timer().running_handle(),
+            sampler.timer().interval_handle(),
+        ))
     }
 
-    pub fn join(self) -> std::thread::Result<()> {
-        self.0.join()
+    pub fn join(mut self) -> std::thread::Result<()> {
+        match &mut self.0 {
+            Inner::Thread(handle, ..) => handle.take().map_or(Ok(()), JoinHandle::join),
+            Inner::Shared(..) => Ok(()),
+        }
     }
 
     pub fn thread(&self) -> &std::thread::Thread {
-        self.0.thread()
+        match &self.0 {
+            Inner::Thread(handle, ..) => handle.as_ref().unwrap().thread(),
+            Inner::Shared(_, _, thread, ..) => thread,
+        }
     }
 
-    pub fn stop(&self) -> Result<(), SendError<()>> {
-        self.1.send(())
+    pub fn stop(&self) -> std::result::Result<(), SendError<()>> {
+        match &self.0 {
+            Inner::Thread(_, tx, ..) => tx.send(Signal::Stop).map_err(|_| SendError(())),
+            Inner::Shared(tasks, id, ..) => {
+                tasks.lock().unwrap().retain(|(i, _)| i != id);
+                Ok(())
+            }
+        }
     }
 
     pub fn is_running(&self) -> bool {
-        self.2.load(Ordering::SeqCst)
+        match &self.0 {
+            Inner::Thread(_, _, running, _) => running.load(Ordering::SeqCst),
+            Inner::Shared(tasks, id, _, running, _) => {
+                running.load(Ordering::SeqCst) && tasks.lock().unwrap().iter().any(|(i, _)| i == id)
+            }
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        *self.interval_handle().lock().unwrap()
+    }
+
+    pub fn set_interval(&self, interval: Duration) -> Result<()> {
+        match &self.0 {
+            Inner::Thread(_, tx, _, handle) => {
+                *handle.lock().unwrap() = interval;
+                let _ = tx.send(Signal::Interval);
+                Ok(())
+            }
+            Inner::Shared(..) => Err(Error::unsupported(
+                "The interval of a shared timer is set on its Sampler",
+            )),
+        }
+    }
+
+    pub(crate) fn interval_handle(&self) -> Arc<Mutex<Duration>> {
+        match &self.0 {
+            Inner::Thread(_, _, _, interval) | Inner::Shared(_, _, _, _, interval) => {
+                interval.clone()
+            }
+        }
+    }
+
+    fn running_handle(&self) -> Arc<AtomicBool> {
+        match &self.0 {
+            Inner::Thread(_, _, running, _) | Inner::Shared(_, _, _, running, _) => running.clone(),
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        let _ = self.stop();
+
+        if let Inner::Thread(handle, ..) = &mut self.0 {
+            if let Some(handle) = handle.take() {
+                if handle.thread().id() != current().id() {
+                    let _ = handle.join();
+                }
+            }
+        }
     }
 }
 
 unsafe impl Send for Timer {}
 unsafe impl Sync for Timer {}
+
+#[cfg(test)]
+mod test {
+    use super::{Sampler, Timer};
+    use std::sync::{mpsc::channel, Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn drop_interrupts_sleep() {
+        let timer = Timer::new(Duration::from_secs(60), || {});
+        std::thread::sleep(Duration::from_millis(50));
+
+        let start = Instant::now();
+        drop(timer);
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn set_interval_wakes_worker() {
+        let (tx, rx) = channel();
+        let timer = Timer::new(Duration::from_secs(60), move || {
+            let _ = tx.send(());
+        });
+        rx.recv().unwrap();
+
+        let start = Instant::now();
+        timer.set_interval(Duration::from_millis(10)).unwrap();
+        rx.recv().unwrap();
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn shared_task_drops_timer() {
+        let sampler = Sampler::new(Duration::from_millis(10));
+        let victim = Arc::new(Mutex::new(Some(Timer::shared(&sampler, || {}))));
+        let (tx, rx) = channel();
+        let tx = Mutex::new(tx);
+        let v = victim.clone();
+
+        let timer = Timer::shared(&sampler, move || {
+            drop(v.lock().unwrap().take());
+            let _ = tx.lock().unwrap().send(());
+        });
+
+        rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert!(victim.lock().unwrap().is_none());
+        assert!(timer.set_interval(Duration::from_secs(1)).is_err());
+        assert_eq!(timer.interval(), Duration::from_millis(10));
+    }
+}
@@ -1,69 +1,205 @@
 #[cfg(unix)]
 use errno::errno;
+use std::sync::Arc;
 
 #[cfg(unix)]
-pub struct ErrnoError(String);
+pub struct ErrnoError(i32, String);
 
 #[cfg(unix)]
 impl ErrnoError {
     pub fn new() -> ErrnoError {
         let e = errno();
 
-        ErrnoError(format!("{}", e))
+        ErrnoError(e.0, format!("{}", e))
     }
-}
 
-#[cfg(unix)]
-impl std::error::Error for ErrnoError {
-    fn description(&self) -> &str {
-        &self.0
+    #[inline]
+    pub fn code(&self) -> i32 {
+        self.0
     }
 }
 
+#[cfg(unix)]
+impl std::error::Error for ErrnoError {}
+
 #[cfg(unix)]
 impl std::fmt::Display for ErrnoError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.1)
     }
 }
 
 #[cfg(unix)]
 impl std::fmt::Debug for ErrnoError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        f.debug_struct("ErrnoError")
+            .field("code", &self.0)
+            .field("description", &self.1)
+            .finish()
+    }
+}
+
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    Unsupported,
+    PermissionDenied,
+    NotFound,
+    NotReady,
+    Parse { source: String, line: Option<usize> },
+    Os(i32),
+    Mach(i32),
+    Other,
+}
+
+impl ErrorKind {
+    #[cfg(unix)]
+    pub(crate) fn from_errno(code: i32) -> ErrorKind {
+        match code {
+            libc::EPERM | libc::EACCES => ErrorKind::PermissionDenied,
+            libc::ENOENT | libc::ENODEV | libc::ENXIO | libc::ESRCH => ErrorKind::NotFound,
+            libc::ENOSYS | libc::ENOTSUP => ErrorKind::Unsupported,
+            code => ErrorKind::Os(code),
+        }
     }
 }
 
-pub struct Error(Box<dyn std::error::Error + Send + Sync>);
+#[derive(Clone)]
+pub struct Error {
+    kind: ErrorKind,
+    source: Arc<dyn std::error::Error + Send + Sync>,
+}
 
 impl Error {
     pub fn new<T>(error: T) -> Error
     where
         T: Into<Box<dyn std::error::Error + Send + Sync>>,
     {
-        Error(error.into())
+        Self::with_kind(ErrorKind::Other, error)
+    }
+
+    pub fn with_kind<T>(kind: ErrorKind, error: T) -> Error
+    where
+        T: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Error {
+            kind,
+            source: Arc::from(error.into()),
+        }
+    }
+
+    pub fn unsupported<T>(error: T) -> Error
+    where
+        T: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self::with_kind(ErrorKind::Unsupported, error)
+    }
+
+    pub fn not_found<T>(error: T) -> Error
+    where
+        T: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self::with_kind(ErrorKind::NotFound, error)
+    }
+
+    pub fn not_ready<T>(error: T) -> Error
+    where
+        T: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self::with_kind(ErrorKind::NotReady, error)
+    }
+
+    pub fn parse<S: Into<String>>(source: S, line: Option<usize>) -> Error {
+        let source = source.into();
+        let message = match line {
+            Some(line) => format!("Cannot parse {} at line {}", source, line),
+            None => format!("Cannot parse {}", source),
+        };
+
+        Self::with_kind(ErrorKind::Parse { source, line }, message)
     }
 
     #[cfg(unix)]
     pub fn from_errno() -> Error {
-        Self::new(ErrnoError::new())
+        let error = ErrnoError::new();
+        Self::with_kind(ErrorKind::from_errno(error.code()), error)
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Error {
+        let kind = match error.kind() {
+            std::io::ErrorKind::NotFound => ErrorKind::NotFound,
+            std::io::ErrorKind::PermissionDenied => ErrorKind::PermissionDenied,
+            std::io::ErrorKind::Unsupported => ErrorKind::Unsupported,
+            _ => match error.raw_os_error() {
+                #[cfg(unix)]
+                Some(code) => ErrorKind::from_errno(code),
+                #[cfg(not(unix))]
+                Some(code) => ErrorKind::Os(code),
+                None => ErrorKind::Other,
+            },
+        };
+
+        Self::with_kind(kind, error)
     }
 }
 
 impl std::error::Error for Error {
-    fn description(&self) -> &str {
-        self.0.description()
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.source)
     }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        std::fmt::Display::fmt(&self.0, f)
+        std::fmt::Display::fmt(&self.source, f)
     }
 }
 
 impl std::fmt::Debug for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        std::fmt::Debug::fmt(&self.0, f)
+        f.debug_struct("Error")
+            .field("kind", &self.kind)
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Error, ErrorKind};
+
+    #[test]
+    fn io_error_kind() {
+        let err = Error::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+        assert_eq!(err.kind(), &ErrorKind::NotFound);
+
+        #[cfg(unix)]
+        {
+            let err = Error::from(std::io::Error::from_raw_os_error(libc::EACCES));
+            assert_eq!(err.kind(), &ErrorKind::PermissionDenied);
+
+            let err = Error::from(std::io::Error::from_raw_os_error(libc::EIO));
+            assert_eq!(err.kind(), &ErrorKind::Os(libc::EIO));
+            let source = std::error::Error::source(&err)
+                .and_then(|source| source.downcast_ref::<std::io::Error>())
+                .and_then(std::io::Error::raw_os_error);
+            assert_eq!(source, Some(libc::EIO));
+        }
+
+        let err = Error::parse("/proc/stat", Some(3));
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::Parse {
+                source: "/proc/stat".to_string(),
+                line: Some(3)
+            }
+        );
+        assert_eq!(err.to_string(), "Cannot parse /proc/stat at line 3");
     }
 }
@@ -0,0 +1,33 @@
+use crate::types::rate::PerSecond;
+
+#[derive(Debug, Clone)]
+pub struct Irq {
+    pub name: String,
+    pub description: String,
+    pub per_cpu: Vec<u64>,
+}
+
+impl Irq {
+    pub fn total(&self) -> u64 {
+        self.per_cpu.iter().sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KernelActivity {
+    pub context_switches: u64,
+    pub interrupts: u64,
+    pub soft_interrupts: u64,
+    pub forks: u64,
+    pub procs_running: u64,
+    pub procs_blocked: u64,
+    pub irqs: Vec<Irq>,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct KernelRates {
+    pub context_switches: PerSecond,
+    pub interrupts: PerSecond,
+    pub soft_interrupts: PerSecond,
+    pub forks: PerSecond,
+}
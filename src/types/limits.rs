@@ -0,0 +1,37 @@
+use std::fmt;
+
+#[derive(Copy, Clone)]
+pub struct Limit {
+    pub used: u64,
+    pub max: u64,
+}
+
+impl Limit {
+    pub fn percent(&self) -> f64 {
+        if self.max == 0 {
+            0f64
+        } else {
+            f64::from(100) * ((self.used as f64) / (self.max as f64))
+        }
+    }
+}
+
+impl fmt::Debug for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Limit")
+            .field("used", &self.used)
+            .field("max", &self.max)
+            .field("percent", &self.percent())
+            .finish()
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Limits {
+    pub files: Limit,
+    pub inodes_allocated: u64,
+    pub inodes_free: u64,
+    pub pids: Limit,
+    pub threads: Limit,
+    pub entropy: Limit,
+}
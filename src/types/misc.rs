@@ -0,0 +1,54 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hypervisor {
+    KVM,
+    QEMU,
+    Xen,
+    VMware,
+    HyperV,
+    VirtualBox,
+    Parallels,
+    Other(String),
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Container {
+    Docker,
+    Podman,
+    LXC,
+    Kubernetes,
+    Other(String),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Environment {
+    pub hypervisor: Option<Hypervisor>,
+    pub container: Option<Container>,
+}
+
+impl Environment {
+    pub fn is_bare_metal(&self) -> bool {
+        self.hypervisor.is_none() && self.container.is_none()
+    }
+
+    pub fn is_virtual_machine(&self) -> bool {
+        self.hypervisor.is_some()
+    }
+
+    pub fn is_container(&self) -> bool {
+        self.container.is_some()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Host {
+    pub hostname: String,
+    pub domainname: Option<String>,
+    pub os_name: String,
+    pub os_version: Option<String>,
+    pub os_id: Option<String>,
+    pub kernel: String,
+    pub arch: String,
+    pub machine_id: Option<String>,
+    pub timezone: Option<String>,
+}
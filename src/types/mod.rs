@@ -1,11 +1,19 @@
 pub mod cpu;
 pub mod error;
 pub mod fs;
+pub mod kernel;
+pub mod limits;
 pub mod mem;
+pub mod misc;
 pub mod network;
 pub mod power;
+pub mod protocols;
+pub mod rate;
+pub mod sockets;
 pub mod thermal;
+pub mod users;
+pub mod wifi;
 
-pub use error::Error;
+pub use error::{Error, ErrorKind};
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -1,7 +1,67 @@
-#[derive(Debug, Copy, Clone)]
+use super::rate::{BytesPerSecond, PerSecond, Ratio};
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Type {
     Wired,
     WiFi,
+    Loopback,
+    Bridge,
+    Bond,
+    Vlan,
+    Tunnel,
+    Virtual,
+    Cellular,
+    Other(String),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Duplex {
+    Full,
+    Half,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Address {
+    pub ip: IpAddr,
+    pub prefix: u8,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct InterfaceStats {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    pub collisions: u64,
+    pub rx_multicast: u64,
+    pub tx_multicast: u64,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct InterfaceRates {
+    pub up: BytesPerSecond,
+    pub down: BytesPerSecond,
+    pub rx_packets: PerSecond,
+    pub tx_packets: PerSecond,
+    pub rx_errors: PerSecond,
+    pub tx_errors: PerSecond,
+    pub rx_dropped: PerSecond,
+    pub tx_dropped: PerSecond,
+    pub utilization: Option<Ratio>,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Wireless {
+    pub quality: Option<f64>,
+    pub signal: Option<i32>,
+    pub noise: Option<i32>,
+    pub frequency: Option<u32>,
+    pub channel: Option<u32>,
+    pub tx_rate: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -12,6 +72,77 @@ pub struct NetworkInterface {
     pub is_up: bool,
     pub bssid: Option<String>,
     pub ssid: Option<String>,
+    pub wireless: Option<Wireless>,
+    /// Bytes sent per tick, scaled to the nominal interval. Use `rates` for per-second values.
     pub up: u64,
+    /// Bytes received per tick, scaled to the nominal interval. Use `rates` for per-second values.
     pub down: u64,
+    /// Counter deltas per tick, scaled to the nominal interval. Use `rates` for per-second values.
+    pub stats: InterfaceStats,
+    pub tx_bytes: u64,
+    pub rx_bytes: u64,
+    pub counters: InterfaceStats,
+    pub rates: Option<InterfaceRates>,
+    pub mac: Option<String>,
+    pub mtu: Option<u32>,
+    pub addresses: Vec<Address>,
+    pub speed: Option<u64>,
+    pub duplex: Option<Duplex>,
+    pub carrier: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Dns {
+    pub nameservers: Vec<IpAddr>,
+    pub search: Vec<String>,
+    pub options: Vec<String>,
+    pub resolved_stub: bool,
+    pub interfaces: HashMap<String, Vec<IpAddr>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vlan {
+    pub parent: String,
+    pub id: u16,
+}
+
+#[derive(Debug, Clone)]
+pub struct Link {
+    pub name: String,
+    pub index: u32,
+    pub master: Option<String>,
+    pub lower: Vec<String>,
+    pub upper: Vec<String>,
+    pub vlan: Option<Vlan>,
+    pub peer_index: Option<u32>,
+}
+
+impl Link {
+    pub fn is_slave(&self) -> bool {
+        self.master.is_some()
+    }
+}
+
+pub const RTF_UP: u32 = 0x0001;
+pub const RTF_GATEWAY: u32 = 0x0002;
+pub const RTF_HOST: u32 = 0x0004;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub destination: IpAddr,
+    pub prefix: u8,
+    pub gateway: Option<IpAddr>,
+    pub interface: String,
+    pub metric: u32,
+    pub flags: u32,
+}
+
+impl Route {
+    pub fn is_default(&self) -> bool {
+        self.prefix == 0
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.flags & RTF_UP != 0
+    }
 }
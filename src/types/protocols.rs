@@ -0,0 +1,100 @@
+use crate::types::rate::PerSecond;
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IpCounters {
+    pub in_receives: u64,
+    pub in_delivers: u64,
+    pub in_discards: u64,
+    pub in_hdr_errors: u64,
+    pub forwarded: u64,
+    pub out_requests: u64,
+    pub out_discards: u64,
+    pub out_no_routes: u64,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TcpCounters {
+    pub active_opens: u64,
+    pub passive_opens: u64,
+    pub attempt_fails: u64,
+    pub estab_resets: u64,
+    pub curr_estab: u64,
+    pub in_segs: u64,
+    pub out_segs: u64,
+    pub retrans_segs: u64,
+    pub in_errs: u64,
+    pub out_rsts: u64,
+    pub listen_overflows: u64,
+    pub listen_drops: u64,
+}
+
+impl TcpCounters {
+    pub fn retransmit_percent(&self) -> f64 {
+        if self.out_segs == 0 {
+            0f64
+        } else {
+            f64::from(100) * ((self.retrans_segs as f64) / (self.out_segs as f64))
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct UdpCounters {
+    pub in_datagrams: u64,
+    pub out_datagrams: u64,
+    pub no_ports: u64,
+    pub in_errors: u64,
+    pub rcvbuf_errors: u64,
+    pub sndbuf_errors: u64,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ProtocolCounters {
+    pub ip: IpCounters,
+    pub tcp: TcpCounters,
+    pub udp: UdpCounters,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct IpRates {
+    pub in_receives: PerSecond,
+    pub in_delivers: PerSecond,
+    pub in_discards: PerSecond,
+    pub in_hdr_errors: PerSecond,
+    pub forwarded: PerSecond,
+    pub out_requests: PerSecond,
+    pub out_discards: PerSecond,
+    pub out_no_routes: PerSecond,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TcpRates {
+    pub active_opens: PerSecond,
+    pub passive_opens: PerSecond,
+    pub attempt_fails: PerSecond,
+    pub estab_resets: PerSecond,
+    pub in_segs: PerSecond,
+    pub out_segs: PerSecond,
+    pub retrans_segs: PerSecond,
+    pub in_errs: PerSecond,
+    pub out_rsts: PerSecond,
+    pub listen_overflows: PerSecond,
+    pub listen_drops: PerSecond,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct UdpRates {
+    pub in_datagrams: PerSecond,
+    pub out_datagrams: PerSecond,
+    pub no_ports: PerSecond,
+    pub in_errors: PerSecond,
+    pub rcvbuf_errors: PerSecond,
+    pub sndbuf_errors: PerSecond,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ProtocolRates {
+    pub ip: IpRates,
+    pub tcp: TcpRates,
+    pub udp: UdpRates,
+}
@@ -0,0 +1,108 @@
+use std::fmt;
+use std::time::Duration;
+
+fn per_second(delta: f64, elapsed: Duration) -> f64 {
+    let secs = elapsed.as_secs_f64();
+
+    if secs > 0f64 {
+        delta / secs
+    } else {
+        0f64
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct BytesPerSecond(pub f64);
+
+impl BytesPerSecond {
+    pub fn from_delta(bytes: u64, elapsed: Duration) -> BytesPerSecond {
+        BytesPerSecond(per_second(bytes as f64, elapsed))
+    }
+
+    pub fn bits(&self) -> f64 {
+        self.0 * 8f64
+    }
+}
+
+impl fmt::Display for BytesPerSecond {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const UNITS: [&str; 5] = ["B/s", "KiB/s", "MiB/s", "GiB/s", "TiB/s"];
+
+        let mut value = self.0;
+        let mut unit = 0;
+        while value.abs() >= 1024f64 && unit < UNITS.len() - 1 {
+            value /= 1024f64;
+            unit += 1;
+        }
+
+        write!(
+            f,
+            "{:.*} {}",
+            f.precision().unwrap_or(2),
+            value,
+            UNITS[unit]
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct PerSecond(pub f64);
+
+impl PerSecond {
+    pub fn from_delta(count: u64, elapsed: Duration) -> PerSecond {
+        PerSecond(per_second(count as f64, elapsed))
+    }
+}
+
+impl fmt::Display for PerSecond {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.*}/s", f.precision().unwrap_or(2), self.0)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Ratio(pub f64);
+
+impl Ratio {
+    pub fn new(part: f64, total: f64) -> Ratio {
+        if total > 0f64 {
+            Ratio(part / total)
+        } else {
+            Ratio(0f64)
+        }
+    }
+
+    pub fn percent(&self) -> f64 {
+        self.0 * 100f64
+    }
+}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.*}%", f.precision().unwrap_or(2), self.percent())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BytesPerSecond, PerSecond, Ratio};
+    use std::time::Duration;
+
+    #[test]
+    fn rate_formatting() {
+        let rate = BytesPerSecond::from_delta(3 * 1024 * 1024, Duration::from_secs(2));
+        assert_eq!(rate, BytesPerSecond(1572864f64));
+        assert_eq!(rate.to_string(), "1.50 MiB/s");
+        assert_eq!(format!("{:.0}", BytesPerSecond(512f64)), "512 B/s");
+
+        let rate = PerSecond::from_delta(5, Duration::from_millis(500));
+        assert_eq!(rate.to_string(), "10.00/s");
+        assert_eq!(
+            PerSecond::from_delta(5, Duration::from_secs(0)),
+            PerSecond(0f64)
+        );
+
+        assert_eq!(Ratio::new(1f64, 8f64).to_string(), "12.50%");
+        assert_eq!(Ratio::new(1f64, 0f64), Ratio(0f64));
+    }
+}
@@ -0,0 +1,62 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Protocol {
+    Tcp,
+    Tcp6,
+    Udp,
+    Udp6,
+    Unix,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TcpState {
+    Established,
+    SynSent,
+    SynRecv,
+    FinWait1,
+    FinWait2,
+    TimeWait,
+    Close,
+    CloseWait,
+    LastAck,
+    Listen,
+    Closing,
+    NewSynRecv,
+    Unknown(u8),
+}
+
+impl From<u8> for TcpState {
+    fn from(state: u8) -> TcpState {
+        match state {
+            0x01 => TcpState::Established,
+            0x02 => TcpState::SynSent,
+            0x03 => TcpState::SynRecv,
+            0x04 => TcpState::FinWait1,
+            0x05 => TcpState::FinWait2,
+            0x06 => TcpState::TimeWait,
+            0x07 => TcpState::Close,
+            0x08 => TcpState::CloseWait,
+            0x09 => TcpState::LastAck,
+            0x0A => TcpState::Listen,
+            0x0B => TcpState::Closing,
+            0x0C => TcpState::NewSynRecv,
+            other => TcpState::Unknown(other),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Socket {
+    pub protocol: Protocol,
+    pub local: Option<SocketAddr>,
+    pub remote: Option<SocketAddr>,
+    pub path: Option<PathBuf>,
+    pub state: Option<TcpState>,
+    pub inode: u64,
+    pub tx_queue: u64,
+    pub rx_queue: u64,
+    pub uid: Option<u32>,
+    pub pid: Option<i32>,
+}
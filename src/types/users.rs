@@ -0,0 +1,11 @@
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub user: String,
+    pub tty: String,
+    pub host: Option<String>,
+    pub login_time: SystemTime,
+    pub pid: i32,
+    pub idle: Option<Duration>,
+}
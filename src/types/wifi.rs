@@ -0,0 +1,42 @@
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Band {
+    Ghz2_4,
+    Ghz5,
+    Ghz6,
+}
+
+impl Band {
+    pub fn from_frequency(frequency: u32) -> Option<Band> {
+        match frequency {
+            2400..=2500 => Some(Band::Ghz2_4),
+            5925..=7125 => Some(Band::Ghz6),
+            4900..=5900 => Some(Band::Ghz5),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Security {
+    Open,
+    WEP,
+    WPAPersonal,
+    WPAEnterprise,
+    WPA2Personal,
+    WPA2Enterprise,
+    WPA3Personal,
+    WPA3Enterprise,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessPoint {
+    pub interface: String,
+    pub ssid: Option<String>,
+    pub bssid: String,
+    pub frequency: Option<u32>,
+    pub channel: Option<u32>,
+    pub band: Option<Band>,
+    pub rssi: Option<i32>,
+    pub security: Security,
+    pub associated: bool,
+}
@@ -0,0 +1,21 @@
+use crate::platform::imp::users;
+use crate::types::users::Session;
+use crate::types::Result;
+
+pub struct Users;
+
+impl Users {
+    pub fn new() -> Users {
+        Users
+    }
+
+    pub fn sessions(&self) -> Result<Vec<Session>> {
+        users::sessions()
+    }
+}
+
+impl Default for Users {
+    fn default() -> Users {
+        Users::new()
+    }
+}
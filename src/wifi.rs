@@ -0,0 +1,21 @@
+use crate::platform::imp::wifi;
+use crate::types::wifi::AccessPoint;
+use crate::types::Result;
+
+pub struct WiFi;
+
+impl WiFi {
+    pub fn new() -> WiFi {
+        WiFi
+    }
+
+    pub fn scan(&self) -> Result<Vec<AccessPoint>> {
+        wifi::scan()
+    }
+}
+
+impl Default for WiFi {
+    fn default() -> WiFi {
+        WiFi::new()
+    }
+}